@@ -0,0 +1,25 @@
+use sort::block_merge_sort::{block_merge_sort, block_merge_sort_by_key};
+
+fn main() {
+    println!("=== 块归并排序 (Block Merge Sort) 演示 ===\n");
+
+    let mut numbers = vec![38, 27, 43, 3, 9, 82, 10, 3, 55, 27];
+    println!("原始: {:?}", numbers);
+    block_merge_sort(&mut numbers);
+    println!("排序后: {:?}", numbers);
+
+    println!();
+
+    // 稳定排序：成绩相同的学生保持原来的先后顺序，并且不需要额外的缓冲区
+    let mut students = vec![
+        ("张三", 85),
+        ("李四", 92),
+        ("王五", 85),
+        ("赵六", 78),
+        ("钱七", 92),
+        ("孙八", 85),
+    ];
+    println!("原始: {:?}", students);
+    block_merge_sort_by_key(&mut students, |&(_, score)| score);
+    println!("按成绩排序后: {:?}", students);
+}
@@ -0,0 +1,47 @@
+use sort::bubble_sort::bubble_sort;
+
+fn main() {
+    println!("=== 冒泡排序 (Bubble Sort) 演示 ===\n");
+
+    // 示例1：整数数组排序
+    let mut numbers = vec![64, 34, 25, 12, 22, 11, 90];
+    println!("原始数组: {:?}", numbers);
+    bubble_sort(&mut numbers);
+    println!("排序后:   {:?}", numbers);
+
+    println!();
+
+    // 示例2：已经有序的数组（最好情况）
+    let mut sorted = vec![1, 2, 3, 4, 5];
+    println!("已有序数组: {:?}", sorted);
+    bubble_sort(&mut sorted);
+    println!("排序后:     {:?}", sorted);
+
+    println!();
+
+    // 示例3：逆序数组（最坏情况）
+    let mut reversed = vec![5, 4, 3, 2, 1];
+    println!("逆序数组: {:?}", reversed);
+    bubble_sort(&mut reversed);
+    println!("排序后:   {:?}", reversed);
+
+    println!();
+
+    // 示例4：字符串数组排序
+    let mut fruits = vec!["banana", "apple", "cherry", "date"];
+    println!("字符串数组: {:?}", fruits);
+    bubble_sort(&mut fruits);
+    println!("排序后:     {:?}", fruits);
+
+    println!();
+
+    // 示例5：空数组和单元素数组
+    let mut empty: Vec<i32> = vec![];
+    let mut single = vec![42];
+    println!("空数组: {:?}", empty);
+    bubble_sort(&mut empty);
+    println!("排序后: {:?}", empty);
+    println!("单元素数组: {:?}", single);
+    bubble_sort(&mut single);
+    println!("排序后:     {:?}", single);
+}
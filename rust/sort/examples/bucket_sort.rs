@@ -0,0 +1,42 @@
+use sort::bucket_sort::{bucket_sort_float, bucket_sort_int};
+
+fn main() {
+    println!("=== 桶排序 (Bucket Sort) 演示 ===\n");
+
+    // 浮点数排序 [0, 1)
+    let mut floats = vec![0.78, 0.17, 0.39, 0.26, 0.72, 0.94, 0.21, 0.12, 0.23, 0.68];
+    println!("浮点数: {:?}", floats);
+    bucket_sort_float(&mut floats);
+    println!("排序后: {:?}", floats);
+
+    println!();
+
+    // 整数排序
+    let mut integers = vec![29, 25, 3, 49, 9, 37, 21, 43];
+    println!("整数: {:?}", integers);
+    bucket_sort_int(&mut integers);
+    println!("排序后: {:?}", integers);
+
+    println!();
+
+    // 展示分桶过程
+    println!("--- 分桶过程演示 ---");
+    let demo = vec![0.42, 0.32, 0.23, 0.52, 0.25, 0.47, 0.51];
+    let n = demo.len();
+    let mut buckets: Vec<Vec<f64>> = vec![Vec::new(); n];
+
+    println!("原始: {:?}", demo);
+    for &num in demo.iter() {
+        let idx = (num * n as f64) as usize;
+        let idx = idx.min(n - 1);
+        println!("  {} -> 桶{}", num, idx);
+        buckets[idx].push(num);
+    }
+
+    println!("\n各桶内容:");
+    for (i, bucket) in buckets.iter().enumerate() {
+        if !bucket.is_empty() {
+            println!("  桶{}: {:?}", i, bucket);
+        }
+    }
+}
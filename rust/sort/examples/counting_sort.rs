@@ -0,0 +1,35 @@
+use sort::counting_sort::{counting_sort, counting_sort_stable};
+
+fn main() {
+    println!("=== 计数排序 (Counting Sort) 演示 ===\n");
+
+    // 非负整数排序
+    let mut numbers: Vec<usize> = vec![4, 2, 2, 8, 3, 3, 1];
+    println!("原始: {:?}", numbers);
+    counting_sort(&mut numbers);
+    println!("排序后: {:?}", numbers);
+
+    println!();
+
+    // 有符号整数排序
+    let mut signed = vec![-5, 3, -1, 0, 3, -5, 2, 1];
+    println!("有符号: {:?}", signed);
+    counting_sort_stable(&mut signed);
+    println!("排序后: {:?}", signed);
+
+    println!();
+
+    // 展示计数过程
+    let mut demo: Vec<usize> = vec![1, 4, 1, 2, 7, 5, 2];
+    println!("演示数组: {:?}", demo);
+
+    let max_val = *demo.iter().max().unwrap();
+    let mut count = vec![0usize; max_val + 1];
+    for &num in demo.iter() {
+        count[num] += 1;
+    }
+    println!("计数数组: {:?}", count);
+
+    counting_sort(&mut demo);
+    println!("排序结果: {:?}", demo);
+}
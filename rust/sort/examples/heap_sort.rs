@@ -0,0 +1,67 @@
+use sort::heap_sort::{
+    heap_sort, heap_sort_d_ary_by, heap_sort_iterative, heap_sort_iterative_by, heapify, HeapArity,
+};
+
+fn main() {
+    println!("=== 堆排序 (Heap Sort) 演示 ===\n");
+
+    let mut numbers = vec![12, 11, 13, 5, 6, 7];
+    println!("原始: {:?}", numbers);
+    heap_sort(&mut numbers);
+    println!("排序后: {:?}", numbers);
+
+    println!();
+
+    // 展示堆构建过程
+    let mut demo = vec![4, 10, 3, 5, 1];
+    println!("堆构建演示:");
+    println!("初始: {:?}", demo);
+
+    let n = demo.len();
+    for i in (0..n / 2).rev() {
+        heapify(&mut demo, n, i);
+        println!("调整节点{}: {:?}", i, demo);
+    }
+    println!("最大堆: {:?}", demo);
+
+    println!();
+
+    // 迭代版本
+    let mut numbers2 = vec![64, 34, 25, 12, 22, 11, 90];
+    println!("原始: {:?}", numbers2);
+    heap_sort_iterative(&mut numbers2);
+    println!("迭代版排序后: {:?}", numbers2);
+
+    println!();
+
+    // 自底向上堆排序和 d 叉堆的比较次数
+    let n = 100_000;
+    let input: Vec<u64> = (0..n).map(|i| (i * 2_654_435_761) % 1_000_003).collect();
+
+    let mut arr = input.clone();
+    let mut comparisons = 0;
+    heap_sort_iterative_by(&mut arr, |a, b| {
+        comparisons += 1;
+        a.cmp(b)
+    });
+    println!("普通堆排序: 比较 {} 次", comparisons);
+
+    for arity in [
+        HeapArity::Binary,
+        HeapArity::Ternary,
+        HeapArity::Quaternary,
+        HeapArity::Octonary,
+    ] {
+        let mut arr = input.clone();
+        let mut comparisons = 0;
+        heap_sort_d_ary_by(&mut arr, arity, |a, b| {
+            comparisons += 1;
+            a.cmp(b)
+        });
+        println!(
+            "自底向上 {} 叉堆: 比较 {} 次",
+            arity.children(),
+            comparisons
+        );
+    }
+}
@@ -0,0 +1,67 @@
+use sort::insertion_sort::{binary_insertion_sort, insertion_sort, insertion_sort_swap};
+
+fn main() {
+    println!("=== 插入排序 (Insertion Sort) 演示 ===\n");
+
+    // 示例1：整数数组排序（移动版本）
+    let mut numbers = vec![12, 11, 13, 5, 6];
+    println!("原始数组: {:?}", numbers);
+    insertion_sort(&mut numbers);
+    println!("插入排序后: {:?}", numbers);
+
+    println!();
+
+    // 示例2：使用交换版本
+    let mut numbers2 = vec![31, 41, 59, 26, 41, 58];
+    println!("原始数组: {:?}", numbers2);
+    insertion_sort_swap(&mut numbers2);
+    println!("交换版本排序后: {:?}", numbers2);
+
+    println!();
+
+    // 示例3：使用二分插入排序
+    let mut numbers3 = vec![37, 23, 0, 17, 12, 72, 31];
+    println!("原始数组: {:?}", numbers3);
+    binary_insertion_sort(&mut numbers3);
+    println!("二分插入排序后: {:?}", numbers3);
+
+    println!();
+
+    // 示例4：已经有序的数组（最好情况）
+    let mut sorted = vec![1, 2, 3, 4, 5, 6, 7, 8];
+    println!("已有序数组: {:?}", sorted);
+    insertion_sort(&mut sorted);
+    println!("排序后: {:?}", sorted);
+
+    println!();
+
+    // 示例5：逆序数组（最坏情况）
+    let mut reversed = vec![8, 7, 6, 5, 4, 3, 2, 1];
+    println!("逆序数组: {:?}", reversed);
+    insertion_sort(&mut reversed);
+    println!("排序后: {:?}", reversed);
+
+    println!();
+
+    // 示例6：字符串排序
+    let mut words = vec!["dog", "cat", "bird", "apple"];
+    println!("字符串数组: {:?}", words);
+    insertion_sort(&mut words);
+    println!("排序后: {:?}", words);
+
+    println!();
+
+    // 示例7：展示插入排序的过程
+    println!("--- 展示排序过程 ---");
+    let mut demo = vec![5, 2, 4, 6, 1, 3];
+    println!("初始: {:?}", demo);
+    for i in 1..demo.len() {
+        let key = demo[i];
+        let mut j = i;
+        while j > 0 && demo[j - 1] > demo[j] {
+            demo.swap(j - 1, j);
+            j -= 1;
+        }
+        println!("第{}轮 (插入{}): {:?}", i, key, demo);
+    }
+}
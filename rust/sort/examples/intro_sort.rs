@@ -0,0 +1,30 @@
+use sort::intro_sort::{intro_sort, intro_sort_by};
+
+fn main() {
+    println!("=== 内省排序 (Introsort) 演示 ===\n");
+
+    let mut numbers = vec![64, 34, 25, 12, 22, 11, 90, 5];
+    println!("原始: {:?}", numbers);
+    intro_sort(&mut numbers);
+    println!("排序后: {:?}", numbers);
+
+    println!();
+
+    // 有序和逆序输入会让普通快速排序退化为 O(n²)
+    let n = 100_000;
+    let mut sorted: Vec<u32> = (0..n).collect();
+    let mut comparisons = 0;
+    intro_sort_by(&mut sorted, |a, b| {
+        comparisons += 1;
+        a.cmp(b)
+    });
+    println!("{} 个已排序元素，比较次数: {}", n, comparisons);
+
+    let mut reversed: Vec<u32> = (0..n).rev().collect();
+    let mut comparisons = 0;
+    intro_sort_by(&mut reversed, |a, b| {
+        comparisons += 1;
+        a.cmp(b)
+    });
+    println!("{} 个逆序元素，比较次数: {}", n, comparisons);
+}
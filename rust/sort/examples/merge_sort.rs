@@ -0,0 +1,64 @@
+use sort::merge_sort::{
+    merge_in_place, merge_sort, merge_sort_in_place, merge_sort_iterative,
+    merge_sort_with_parallelism,
+};
+use sort::Parallelism;
+use std::time::Instant;
+
+fn main() {
+    println!("=== 归并排序 (Merge Sort) 演示 ===\n");
+
+    // 递归版本
+    let mut numbers = vec![38, 27, 43, 3, 9, 82, 10];
+    println!("原始数组: {:?}", numbers);
+    merge_sort(&mut numbers);
+    println!("递归排序后: {:?}", numbers);
+
+    println!();
+
+    // 迭代版本
+    let mut numbers2 = vec![64, 34, 25, 12, 22, 11, 90];
+    println!("原始数组: {:?}", numbers2);
+    merge_sort_iterative(&mut numbers2);
+    println!("迭代排序后: {:?}", numbers2);
+
+    println!();
+
+    // 不使用缓冲区：二分查找加旋转原地合并
+    let mut halves = vec![3, 8, 12, 20, 1, 9, 10, 25];
+    println!("两个有序段: {:?}", halves);
+    merge_in_place(&mut halves, 4);
+    println!("原地合并后: {:?}", halves);
+
+    let mut numbers3 = vec![91, 12, 45, 7, 63, 28, 7, 84];
+    println!("原始数组: {:?}", numbers3);
+    merge_sort_in_place(&mut numbers3);
+    println!("原地排序后: {:?}", numbers3);
+
+    println!();
+
+    // 字符串排序
+    let mut words = vec!["dog", "cat", "bird", "apple", "zebra"];
+    println!("字符串: {:?}", words);
+    merge_sort(&mut words);
+    println!("排序后: {:?}", words);
+
+    println!();
+
+    // 并行版本：各线程先排序一段，再用 merge path 并行合并，结果与 merge_sort 相同
+    let n = 500_000u64;
+    let input: Vec<u64> = (0..n)
+        .map(|i| i.wrapping_mul(0x9E37_79B9_7F4A_7C15) >> 40)
+        .collect();
+    let mut expected = input.clone();
+    let start = Instant::now();
+    merge_sort(&mut expected);
+    println!("{} 个元素，单线程: {:?}", n, start.elapsed());
+    for threads in [2, 4, 8] {
+        let mut arr = input.clone();
+        let start = Instant::now();
+        merge_sort_with_parallelism(&mut arr, Parallelism::new(threads));
+        assert_eq!(arr, expected);
+        println!("{} 个元素，{} 个线程: {:?}", n, threads, start.elapsed());
+    }
+}
@@ -0,0 +1,57 @@
+use sort::pdq_sort::pdq_sort;
+use sort::quick_sort::{quick_sort, quick_sort_3way, quick_sort_hoare};
+use std::time::Instant;
+
+type SortFn = fn(&mut [u64]);
+
+/// 简单的线性同余伪随机数，保证每次运行的数据相同
+fn pseudo_random(n: usize, bound: u64) -> Vec<u64> {
+    let mut x: u64 = 0x2545_f491_4f6c_dd1d;
+    (0..n)
+        .map(|_| {
+            x = x
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            (x >> 33) % bound
+        })
+        .collect()
+}
+
+fn main() {
+    println!("=== 模式消除快速排序 (pdqsort) 演示 ===\n");
+
+    let mut numbers = vec![64, 34, 25, 12, 22, 11, 90, 5];
+    println!("原始: {:?}", numbers);
+    pdq_sort(&mut numbers);
+    println!("排序后: {:?}", numbers);
+
+    println!();
+
+    // Lomuto 分区在有序、重复输入上是 O(n²)，规模太大时会非常慢甚至栈溢出
+    let n = 20_000;
+    let inputs: Vec<(&str, Vec<u64>)> = vec![
+        ("随机", pseudo_random(n, u64::MAX)),
+        ("有序", (0..n as u64).collect()),
+        ("逆序", (0..n as u64).rev().collect()),
+        ("锯齿", (0..n as u64).map(|i| i % 1000).collect()),
+        ("大量重复", pseudo_random(n, 8)),
+    ];
+    let algorithms: [(&str, SortFn); 4] = [
+        ("quick_sort", quick_sort),
+        ("quick_sort_hoare", quick_sort_hoare),
+        ("quick_sort_3way", quick_sort_3way),
+        ("pdq_sort", pdq_sort),
+    ];
+
+    println!("{} 个元素的耗时:", n);
+    for (pattern, input) in &inputs {
+        print!("{:<8}", pattern);
+        for (name, sort) in algorithms {
+            let mut arr = input.clone();
+            let start = Instant::now();
+            sort(&mut arr);
+            print!("  {}: {:>8.3?}", name, start.elapsed());
+        }
+        println!();
+    }
+}
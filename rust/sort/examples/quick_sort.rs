@@ -0,0 +1,67 @@
+use sort::quick_sort::{
+    quick_sort, quick_sort_3way, quick_sort_block, quick_sort_dual_pivot, quick_sort_hoare,
+    quick_sort_with_parallelism, quick_sort_with_scheme, PartitionScheme,
+};
+use sort::Parallelism;
+use std::time::Instant;
+
+fn main() {
+    println!("=== 快速排序 (Quick Sort) 演示 ===\n");
+
+    // Lomuto分区
+    let mut numbers = vec![10, 7, 8, 9, 1, 5];
+    println!("原始: {:?}", numbers);
+    quick_sort(&mut numbers);
+    println!("Lomuto排序后: {:?}", numbers);
+
+    println!();
+
+    // Hoare分区
+    let mut numbers2 = vec![64, 34, 25, 12, 22, 11, 90];
+    println!("原始: {:?}", numbers2);
+    quick_sort_hoare(&mut numbers2);
+    println!("Hoare排序后: {:?}", numbers2);
+
+    println!();
+
+    // 三路快排
+    let mut with_dups = vec![4, 2, 4, 1, 4, 3, 4, 2];
+    println!("有重复元素: {:?}", with_dups);
+    quick_sort_3way(&mut with_dups);
+    println!("三路快排后: {:?}", with_dups);
+
+    println!();
+
+    // 双轴快排
+    let mut numbers3 = vec![38, 27, 43, 3, 9, 82, 10, 3, 55, 27];
+    println!("原始: {:?}", numbers3);
+    quick_sort_dual_pivot(&mut numbers3);
+    println!("双轴快排后: {:?}", numbers3);
+
+    println!();
+
+    // 分块分区：先比较、记录放错的元素，再成批交换
+    let mut numbers4 = vec![91, 12, 45, 7, 63, 28, 7, 84, 39, 50];
+    println!("原始: {:?}", numbers4);
+    quick_sort_with_scheme(&mut numbers4, PartitionScheme::Block);
+    println!("分块分区排序后: {:?}", numbers4);
+
+    println!();
+
+    // 并行快排：较大的一侧交给工作窃取线程池，其他线程空闲时取走
+    let n = 300_000u64;
+    let input: Vec<u64> = (0..n)
+        .map(|i| i.wrapping_mul(0x9E37_79B9_7F4A_7C15))
+        .collect();
+    let mut expected = input.clone();
+    let start = Instant::now();
+    quick_sort_block(&mut expected);
+    println!("{} 个元素，单线程: {:?}", n, start.elapsed());
+    for threads in [2, 4, 8] {
+        let mut arr = input.clone();
+        let start = Instant::now();
+        quick_sort_with_parallelism(&mut arr, Parallelism::new(threads));
+        assert_eq!(arr, expected);
+        println!("{} 个元素，{} 个线程: {:?}", n, threads, start.elapsed());
+    }
+}
@@ -0,0 +1,66 @@
+use sort::radix_sort::{
+    counting_sort_by_byte, radix_sort, radix_sort_msd, radix_sort_with_parallelism,
+};
+use sort::{Order, Parallelism, RadixKey};
+use std::time::Instant;
+
+fn main() {
+    println!("=== 基数排序 (Radix Sort) 演示 ===\n");
+
+    // LSD版本
+    let mut numbers: Vec<u32> = vec![170, 45, 75, 90, 802, 24, 2, 66];
+    println!("原始: {:?}", numbers);
+    radix_sort(&mut numbers);
+    println!("LSD排序后: {:?}", numbers);
+
+    println!();
+
+    // MSD版本
+    let mut numbers2: Vec<u32> = vec![170, 45, 75, 90, 802, 24, 2, 66];
+    println!("原始: {:?}", numbers2);
+    radix_sort_msd(&mut numbers2);
+    println!("MSD排序后: {:?}", numbers2);
+
+    println!();
+
+    // 有符号整数、浮点数同样适用
+    let mut signed: Vec<i32> = vec![-170, 45, -75, 90, 0, -2];
+    println!("原始: {:?}", signed);
+    radix_sort(&mut signed);
+    println!("有符号排序后: {:?}", signed);
+
+    let mut floats = vec![0.5, -1.25, 3.0, -0.0, 2.75];
+    println!("原始: {:?}", floats);
+    radix_sort(&mut floats);
+    println!("浮点数排序后: {:?}", floats);
+
+    println!();
+
+    // 展示LSD过程（十六进制显示，每两位是一个字节）
+    let mut demo: Vec<u32> = vec![0x3a29, 0x1457, 0x3a57, 0x0839, 0x1436, 0x0720, 0x3355];
+    println!("演示: {:04x?}", demo);
+
+    for index in 0..<u32 as RadixKey>::BYTES {
+        counting_sort_by_byte(&mut demo, index);
+        println!("按第{}字节排序: {:04x?}", index, demo);
+    }
+
+    println!();
+
+    // 并行LSD：各线程统计自己那一段的直方图，前缀和确定位置后并发分发，结果与单线程相同
+    let n = 1_000_000u64;
+    let input: Vec<u64> = (0..n)
+        .map(|i| i.wrapping_mul(0x9E37_79B9_7F4A_7C15))
+        .collect();
+    let mut expected = input.clone();
+    let start = Instant::now();
+    radix_sort(&mut expected);
+    println!("{} 个元素，单线程: {:?}", n, start.elapsed());
+    for threads in [2, 4, 8] {
+        let mut arr = input.clone();
+        let start = Instant::now();
+        radix_sort_with_parallelism(&mut arr, Parallelism::new(threads), Order::Ascending);
+        assert_eq!(arr, expected);
+        println!("{} 个元素，{} 个线程: {:?}", n, threads, start.elapsed());
+    }
+}
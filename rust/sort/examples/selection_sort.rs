@@ -0,0 +1,54 @@
+use sort::selection_sort::{selection_sort, selection_sort_bidirectional};
+
+fn main() {
+    println!("=== 选择排序 (Selection Sort) 演示 ===\n");
+
+    // 示例1：整数数组排序
+    let mut numbers = vec![64, 25, 12, 22, 11];
+    println!("原始数组: {:?}", numbers);
+    selection_sort(&mut numbers);
+    println!("排序后:   {:?}", numbers);
+
+    println!();
+
+    // 示例2：使用双向选择排序
+    let mut numbers2 = vec![29, 10, 14, 37, 13, 8, 25];
+    println!("原始数组: {:?}", numbers2);
+    selection_sort_bidirectional(&mut numbers2);
+    println!("双向选择排序后: {:?}", numbers2);
+
+    println!();
+
+    // 示例3：带有重复元素的数组
+    let mut with_duplicates = vec![4, 2, 4, 1, 3, 2, 1];
+    println!("带重复元素: {:?}", with_duplicates);
+    selection_sort(&mut with_duplicates);
+    println!("排序后:     {:?}", with_duplicates);
+
+    println!();
+
+    // 示例4：字符排序
+    let mut chars = vec!['d', 'b', 'a', 'c', 'e'];
+    println!("字符数组: {:?}", chars);
+    selection_sort(&mut chars);
+    println!("排序后:   {:?}", chars);
+
+    println!();
+
+    // 示例5：边界情况
+    let mut empty: Vec<i32> = vec![];
+    let mut single = vec![42];
+    let mut two = vec![2, 1];
+
+    println!("空数组: {:?}", empty);
+    selection_sort(&mut empty);
+    println!("排序后: {:?}", empty);
+
+    println!("单元素: {:?}", single);
+    selection_sort(&mut single);
+    println!("排序后: {:?}", single);
+
+    println!("两元素: {:?}", two);
+    selection_sort(&mut two);
+    println!("排序后: {:?}", two);
+}
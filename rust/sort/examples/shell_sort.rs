@@ -0,0 +1,47 @@
+use sort::shell_sort::{shell_sort, shell_sort_with_gaps_by, GapSequence};
+
+fn main() {
+    println!("=== 希尔排序演示 ===\n");
+
+    let mut numbers = vec![12, 34, 54, 2, 3, 8, 9, 1, 5, 7];
+    println!("原始: {:?}", numbers);
+    shell_sort(&mut numbers);
+    println!("排序后: {:?}", numbers);
+
+    println!();
+
+    // 不同增量序列的比较次数
+    let n = 10_000;
+    let mut state = 0x9E37_79B9_7F4A_7C15u64;
+    let input: Vec<u64> = (0..n)
+        .map(|_| {
+            // xorshift 伪随机数
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state % 1_000_000
+        })
+        .collect();
+    for gaps in [
+        GapSequence::Shell,
+        GapSequence::Hibbard,
+        GapSequence::Knuth,
+        GapSequence::Sedgewick,
+        GapSequence::Tokuda,
+        GapSequence::Ciura,
+        GapSequence::Pratt,
+    ] {
+        let mut arr = input.clone();
+        let mut comparisons = 0;
+        shell_sort_with_gaps_by(&mut arr, &gaps, |a, b| {
+            comparisons += 1;
+            a.cmp(b)
+        });
+        println!(
+            "{:?}: {} 个增量，比较次数: {}",
+            gaps,
+            gaps.gaps(arr.len()).len(),
+            comparisons
+        );
+    }
+}
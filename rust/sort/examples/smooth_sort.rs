@@ -0,0 +1,32 @@
+use sort::smooth_sort::{smooth_sort, smooth_sort_by};
+
+fn main() {
+    println!("=== 平滑排序 (Smoothsort) 演示 ===\n");
+
+    let mut numbers = vec![64, 34, 25, 12, 22, 11, 90, 5];
+    println!("原始: {:?}", numbers);
+    smooth_sort(&mut numbers);
+    println!("排序后: {:?}", numbers);
+
+    println!();
+
+    // 越接近有序，比较次数越接近线性
+    let n = 100_000;
+    for swaps in [0, 100, 1000, 10_000] {
+        let mut arr: Vec<u32> = (0..n).collect();
+        for k in 0..swaps {
+            let i = (k * 7919) % (n as usize - 1);
+            arr.swap(i, i + 1);
+        }
+
+        let mut comparisons = 0;
+        smooth_sort_by(&mut arr, |a, b| {
+            comparisons += 1;
+            a.cmp(b)
+        });
+        println!(
+            "{} 个元素，{} 处相邻交换，比较次数: {}",
+            n, swaps, comparisons
+        );
+    }
+}
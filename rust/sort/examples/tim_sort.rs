@@ -0,0 +1,42 @@
+use sort::merge_sort::merge_sort_by;
+use sort::tim_sort::{tim_sort, tim_sort_by};
+
+fn main() {
+    println!("=== Timsort 演示 ===\n");
+
+    let mut numbers = vec![64, 34, 25, 12, 22, 11, 90, 5];
+    println!("原始: {:?}", numbers);
+    tim_sort(&mut numbers);
+    println!("排序后: {:?}", numbers);
+
+    println!();
+
+    // 由几个有序块拼接而成的数据，Timsort 直接利用已有的有序段
+    let n = 100_000u32;
+    let chunks = 4;
+    let input: Vec<u32> = (0..n)
+        .map(|i| (i % (n / chunks)) * chunks + i / (n / chunks))
+        .collect();
+
+    let mut arr = input.clone();
+    let mut comparisons = 0;
+    tim_sort_by(&mut arr, |a, b| {
+        comparisons += 1;
+        a.cmp(b)
+    });
+    println!(
+        "{} 个有序块拼接的 {} 个元素，Timsort 比较次数: {}",
+        chunks, n, comparisons
+    );
+
+    let mut arr = input;
+    let mut comparisons = 0;
+    merge_sort_by(&mut arr, |a, b| {
+        comparisons += 1;
+        a.cmp(b)
+    });
+    println!(
+        "{} 个有序块拼接的 {} 个元素，归并排序比较次数: {}",
+        chunks, n, comparisons
+    );
+}
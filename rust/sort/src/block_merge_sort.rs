@@ -0,0 +1,447 @@
+//! 块归并排序 (Block Merge Sort / WikiSort)
+//!
+//! # 算法原理
+//! 普通归并排序需要 O(n) 的缓冲区，块归并排序改用数组自身的一部分作为缓冲区：
+//! 1. 从数组中抽出约 2√n 个互不相同的元素放到最前面，前一半用作标签，后一半用作合并缓冲区。
+//!    抽出的是每个值第一次出现的元素，整个过程只做旋转，其余元素的相对顺序不变
+//! 2. 其余元素自底向上归并。合并相邻的有序段 A 和 B 时，把 A 切成约 √|A| 大小的块，
+//!    每块的第一个元素与一个标签交换，记住块原来的先后顺序
+//! 3. A 块逐个与后面的 B 块交换、向右“滚动”，前面的 B 块最后一个元素不小于剩余 A 块的
+//!    最小首元素时，把这个 A 块“放下”，与它和上一个 A 块之间的 B 元素借助缓冲区局部合并
+//! 4. 全部归并完后，把缓冲区排好序，再通过旋转把它放回数组中的正确位置
+//!
+//! 互不相同的元素不够时只用它们作标签、加大块的大小，局部合并改为二分查找加旋转；
+//! 这时每个值最多让一次旋转发生，代价依然是线性的。
+//! 不超过 32 个元素的 A 段直接用固定大小的栈上缓冲区合并，复用归并排序的合并函数。
+//!
+//! # 时间复杂度: O(n log n) - 所有情况
+//! # 空间复杂度: O(1) - 只用一个固定大小的栈上缓冲区
+//! # 稳定性: 稳定排序
+
+use crate::insertion_sort::insertion_sort_by;
+use crate::merge_sort::merge_adjacent_by;
+use crate::Order;
+use std::cmp::Ordering;
+use std::mem::MaybeUninit;
+use std::ops::Range;
+
+/// 初始有序段的长度，也是直接用插入排序的阈值
+const RUN_LEN: usize = 16;
+
+/// 栈上缓冲区的容量，A 段不超过它时直接合并
+const CACHE_LEN: usize = 32;
+
+/// 块归并排序主函数
+pub fn block_merge_sort<T: Ord>(arr: &mut [T]) {
+    block_merge_sort_by(arr, T::cmp);
+}
+
+/// 按指定方向排序的块归并排序，降序时依然稳定
+pub fn block_merge_sort_with_order<T: Ord>(arr: &mut [T], order: Order) {
+    block_merge_sort_by(arr, |a, b| order.apply(a.cmp(b)));
+}
+
+/// 使用自定义比较函数的块归并排序
+///
+/// 只有 `compare` 返回 `Ordering::Less` 时，后面的元素才会越过前面的元素，因此保持稳定。
+pub fn block_merge_sort_by<T, F>(arr: &mut [T], mut compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let n = arr.len();
+    if n <= RUN_LEN {
+        insertion_sort_by(arr, &mut compare);
+        return;
+    }
+
+    // 第一步：抽出互不相同的元素。足够时前 k 个作标签、后 k 个作缓冲区，否则全部用作标签
+    let k = n.isqrt() + 1;
+    let keys = collect_keys(arr, 2 * k, &mut compare);
+    let (tags, buffer_len) = if keys == 2 * k { (k, k) } else { (keys, 0) };
+
+    // 第二步：其余元素先按 RUN_LEN 分段插入排序，再自底向上两两归并
+    for run in arr[keys..].chunks_mut(RUN_LEN) {
+        insertion_sort_by(run, &mut compare);
+    }
+    let merge = BlockMerge {
+        tags,
+        buffer: tags..tags + buffer_len,
+    };
+    let mut cache: [MaybeUninit<T>; CACHE_LEN] = [const { MaybeUninit::uninit() }; CACHE_LEN];
+    let mut width = RUN_LEN;
+    while width < n - keys {
+        let mut start = keys;
+        while start + width < n {
+            let mid = start + width;
+            let end = (mid + width).min(n);
+            merge.merge(arr, start, mid, end, &mut cache, &mut compare);
+            start = end;
+        }
+        width *= 2;
+    }
+
+    // 第三步：缓冲区的顺序在合并时被打乱，排好序后放回数组。
+    // 抽出的都是每个值第一次出现的元素，相等时排在前面
+    insertion_sort_by(&mut arr[..keys], &mut compare);
+    merge_in_place(arr, 0, keys, n, &mut compare);
+}
+
+/// 按键排序的块归并排序，比较 `key(a)` 与 `key(b)`
+pub fn block_merge_sort_by_key<T, K, F>(arr: &mut [T], mut key: F)
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    block_merge_sort_by(arr, |a, b| key(a).cmp(&key(b)));
+}
+
+/// 从左到右收集最多 `want` 个互不相同的元素，按升序移到数组最前面，返回收集到的个数
+///
+/// 已收集的元素作为一个整体向右旋转，其余元素的相对顺序不变；
+/// 每个值只收集第一次出现的那个元素。
+fn collect_keys<T, F>(arr: &mut [T], want: usize, compare: &mut F) -> usize
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    // 已收集的元素位于 `arr[first..first + count]`
+    let mut first = 0;
+    let mut count = 1;
+    for i in 1..arr.len() {
+        if count == want {
+            break;
+        }
+        let pos = arr[first..first + count].partition_point(|key| compare(key, &arr[i]).is_lt());
+        if pos < count && compare(&arr[first + pos], &arr[i]).is_eq() {
+            continue;
+        }
+        arr[first..i].rotate_left(count);
+        first = i - count;
+        arr[first + pos..=i].rotate_right(1);
+        count += 1;
+    }
+    arr[..first + count].rotate_left(first);
+    count
+}
+
+/// 一次合并用到的内部缓冲区：`arr[..tags]` 是标签，`buffer` 是合并缓冲区（可能为空）
+struct BlockMerge {
+    tags: usize,
+    buffer: Range<usize>,
+}
+
+impl BlockMerge {
+    /// 合并相邻的有序段 `arr[start..mid]` 和 `arr[mid..end]`
+    fn merge<T, F>(
+        &self,
+        arr: &mut [T],
+        start: usize,
+        mid: usize,
+        end: usize,
+        cache: &mut [MaybeUninit<T>],
+        compare: &mut F,
+    ) where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        // 已经有序，或者 A 整体大于 B
+        if !compare(&arr[mid], &arr[mid - 1]).is_lt() {
+            return;
+        }
+        if compare(&arr[end - 1], &arr[start]).is_lt() {
+            arr[start..end].rotate_left(mid - start);
+            return;
+        }
+
+        let a_len = mid - start;
+        if a_len <= cache.len() {
+            merge_adjacent_by(&mut arr[start..end], a_len, cache, compare);
+            return;
+        }
+        let buffer = self.buffer.start;
+        let has_buffer = !self.buffer.is_empty();
+        if a_len <= self.buffer.len() {
+            block_swap(arr, start, buffer, a_len);
+            merge_internal(arr, start, mid, end, buffer, compare);
+            return;
+        }
+
+        // 块的大小约为 √|A|，同时保证每个完整的 A 块都能分到一个标签
+        let block = a_len.isqrt().max(a_len.div_ceil(self.tags));
+        debug_assert!(!has_buffer || block <= self.buffer.len());
+
+        // 开头不满一块的部分留在原处，其余每块的第一个元素与标签交换
+        let first_len = a_len % block;
+        for (tag, index) in (start + first_len..mid).step_by(block).enumerate() {
+            arr.swap(tag, index);
+        }
+
+        // 有缓冲区时，等待合并的 A 块总是放在缓冲区里，它原来的位置只是占位
+        let mut last_a = start..start + first_len;
+        let mut last_b = start..start;
+        let (mut a_lo, mut a_hi) = (start + first_len, mid);
+        let mut b_block = mid..(mid + block).min(end);
+        let mut next_tag = 0;
+        if has_buffer {
+            block_swap(arr, last_a.start, buffer, last_a.len());
+        }
+
+        while a_lo < a_hi {
+            // `arr[next_tag]` 现在是剩余 A 块中最小的首元素
+            if (!last_b.is_empty() && !compare(&arr[last_b.end - 1], &arr[next_tag]).is_lt())
+                || b_block.is_empty()
+            {
+                // 上一个 B 块中小于这个首元素的部分留在前面，其余的放到 A 块后面
+                let b_split = last_b.start
+                    + arr[last_b.clone()].partition_point(|x| compare(x, &arr[next_tag]).is_lt());
+                let b_remaining = last_b.end - b_split;
+
+                // 标签最小的就是原来最靠前的 A 块，把它换到最前面并换回首元素
+                let mut min_a = a_lo;
+                for find in (a_lo + block..a_hi).step_by(block) {
+                    if compare(&arr[find], &arr[min_a]).is_lt() {
+                        min_a = find;
+                    }
+                }
+                block_swap(arr, a_lo, min_a, block);
+                arr.swap(a_lo, next_tag);
+                next_tag += 1;
+
+                // 上一个 A 块与它后面、`b_split` 之前的 B 元素合并
+                if has_buffer {
+                    merge_internal(arr, last_a.start, last_a.end, b_split, buffer, compare);
+                    // 这个 A 块移进缓冲区后原位置只剩占位元素，不必旋转，直接交换即可
+                    block_swap(arr, a_lo, buffer, block);
+                    block_swap(arr, b_split, a_lo + block - b_remaining, b_remaining);
+                } else {
+                    merge_in_place(arr, last_a.start, last_a.end, b_split, compare);
+                    arr[b_split..a_lo + block].rotate_left(b_remaining);
+                }
+
+                last_a = b_split..b_split + block;
+                last_b = last_a.end..last_a.end + b_remaining;
+                a_lo += block;
+            } else if b_block.len() < block {
+                // 最后一个不满一块的 B 块移到剩余 A 块之前
+                let len = b_block.len();
+                arr[a_lo..b_block.end].rotate_right(len);
+                last_b = a_lo..a_lo + len;
+                a_lo += len;
+                a_hi += len;
+                b_block = end..end;
+            } else {
+                // 最左边的 A 块与下一个 B 块交换，滚到剩余 A 块的末尾
+                block_swap(arr, a_lo, b_block.start, block);
+                last_b = a_lo..a_lo + block;
+                a_lo += block;
+                a_hi += block;
+                b_block = b_block.start + block..(b_block.end + block).min(end);
+            }
+        }
+
+        // 最后一个 A 块与剩余的 B 元素合并
+        if has_buffer {
+            merge_internal(arr, last_a.start, last_a.end, end, buffer, compare);
+        } else {
+            merge_in_place(arr, last_a.start, last_a.end, end, compare);
+        }
+    }
+}
+
+/// 借助内部缓冲区合并：A 的元素在 `arr[buffer..]`，`arr[start..mid]` 只是占位，
+/// B 是 `arr[mid..end]`
+///
+/// 每次把较小的元素与输出位置上的元素交换，结束后占位元素全部回到缓冲区（顺序被打乱）。
+/// 两侧元素相等时优先取 A 的元素，保证稳定性。
+fn merge_internal<T, F>(
+    arr: &mut [T],
+    start: usize,
+    mid: usize,
+    end: usize,
+    buffer: usize,
+    compare: &mut F,
+) where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let a_len = mid - start;
+    let (mut a, mut b, mut out) = (0, mid, start);
+    if a_len > 0 && b < end {
+        loop {
+            if compare(&arr[b], &arr[buffer + a]).is_lt() {
+                arr.swap(out, b);
+                b += 1;
+                out += 1;
+                if b == end {
+                    break;
+                }
+            } else {
+                arr.swap(out, buffer + a);
+                a += 1;
+                out += 1;
+                if a == a_len {
+                    break;
+                }
+            }
+        }
+    }
+    // B 先用完时，A 剩余的元素从缓冲区换回来
+    block_swap(arr, buffer + a, out, a_len - a);
+}
+
+/// 不借助缓冲区合并 `arr[start..mid]` 和 `arr[mid..end]`
+///
+/// 用二分查找找出 B 中小于 A 首元素的部分，旋转到 A 前面，再跳过 A 中不大于 B 首元素的部分。
+/// 每次旋转后 A 至少有一个值完全就位，A 中的不同值较少时接近线性。
+fn merge_in_place<T, F>(
+    arr: &mut [T],
+    mut start: usize,
+    mut mid: usize,
+    end: usize,
+    compare: &mut F,
+) where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    while start < mid && mid < end {
+        let split = mid + arr[mid..end].partition_point(|x| compare(x, &arr[start]).is_lt());
+        arr[start..split].rotate_left(mid - start);
+        start += split - mid;
+        mid = split;
+        if mid == end {
+            break;
+        }
+        start += arr[start..mid].partition_point(|x| !compare(&arr[mid], x).is_lt());
+    }
+}
+
+/// 交换 `arr[x..x + len]` 与 `arr[y..y + len]`，两段不重叠或完全相同
+fn block_swap<T>(arr: &mut [T], x: usize, y: usize, len: usize) {
+    for i in 0..len {
+        arr.swap(x + i, y + i);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{assert_each_once, random_vec, tracked_vec, Tracked};
+    use std::panic::{self, AssertUnwindSafe};
+
+    #[test]
+    fn test_block_merge_sort() {
+        let mut arr = vec![38, 27, 43, 3, 9, 82, 10];
+        block_merge_sort(&mut arr);
+        assert_eq!(arr, vec![3, 9, 10, 27, 38, 43, 82]);
+
+        let mut arr: Vec<i32> = vec![];
+        block_merge_sort(&mut arr);
+        assert_eq!(arr, vec![]);
+
+        // 不同值的个数覆盖缓冲区充足、只够作标签和只有一两个值的情况
+        for bound in [1, 2, 5, 40, 1_000_000] {
+            for len in (0..300).chain([1000, 4096, 5000]) {
+                let input = random_vec(len, bound, len as u64 * 7 + bound);
+                let mut expected = input.clone();
+                expected.sort();
+
+                let mut arr = input.clone();
+                block_merge_sort(&mut arr);
+                assert_eq!(arr, expected, "len = {}, bound = {}", len, bound);
+
+                let mut arr = input;
+                block_merge_sort_with_order(&mut arr, Order::Descending);
+                expected.reverse();
+                assert_eq!(arr, expected, "len = {}, bound = {}", len, bound);
+            }
+        }
+
+        let input = random_vec(100_000, 1_000_000, 91);
+        let mut expected = input.clone();
+        expected.sort();
+        let mut arr = input;
+        block_merge_sort(&mut arr);
+        assert_eq!(arr, expected);
+    }
+
+    #[test]
+    fn test_stability() {
+        // 按键排序后，键相同的元素保持原来的先后顺序
+        for (len, bound) in [(100, 10), (5000, 30), (5000, 5000), (20_000, 300)] {
+            let keys = random_vec(len, bound, len as u64 + bound);
+            let mut arr: Vec<(u64, usize)> = keys.into_iter().zip(0..).collect();
+            block_merge_sort_by_key(&mut arr, |&(key, _)| key);
+            assert!(
+                arr.windows(2).all(|w| w[0] < w[1]),
+                "len = {}, bound = {}",
+                len,
+                bound
+            );
+        }
+    }
+
+    #[test]
+    fn test_comparisons_are_n_log_n() {
+        // 不同值只有几十个时走旋转合并，比较次数依然是 O(n log n)
+        for bound in [40, 1_000_000] {
+            let n = 100_000;
+            let mut arr = random_vec(n, bound, 92);
+            let mut calls = 0;
+            block_merge_sort_by(&mut arr, |a, b| {
+                calls += 1;
+                a.cmp(b)
+            });
+            assert!(arr.windows(2).all(|w| w[0] <= w[1]));
+            assert!(calls < 4 * n * 17, "bound = {}: 比较 {} 次", bound, calls);
+        }
+    }
+
+    #[test]
+    fn test_moves_without_clone() {
+        let (mut arr, drops) = tracked_vec(&random_vec(3000, 100, 93));
+        block_merge_sort(&mut arr);
+        assert!(arr.windows(2).all(|w| w[0].key <= w[1].key));
+        assert!(arr
+            .windows(2)
+            .all(|w| w[0].key < w[1].key || w[0].id < w[1].id));
+        assert_each_once(arr, &drops);
+    }
+
+    #[test]
+    fn test_zero_sized_elements() {
+        #[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+        struct Zst;
+
+        let mut arr: Vec<Zst> = (0..3000).map(|_| Zst).collect();
+        block_merge_sort(&mut arr);
+        assert_eq!(arr.len(), 3000);
+
+        // 比较结果不一致时也不能越界或重复释放
+        let mut state = 0x9E37_79B9_7F4A_7C15u64;
+        block_merge_sort_by(&mut arr, |_, _| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            if state & 1 == 0 {
+                Ordering::Less
+            } else {
+                Ordering::Greater
+            }
+        });
+        assert_eq!(arr.len(), 3000);
+    }
+
+    #[test]
+    fn test_panic_in_compare_keeps_every_element() {
+        let keys = random_vec(3000, 1000, 94);
+        for limit in [1, 100, 5000, 30_000] {
+            let (mut arr, drops) = tracked_vec(&keys);
+            let mut calls = 0;
+            let result = panic::catch_unwind(AssertUnwindSafe(|| {
+                block_merge_sort_by(&mut arr, |a: &Tracked, b: &Tracked| {
+                    calls += 1;
+                    assert!(calls < limit, "比较函数 panic");
+                    a.cmp(b)
+                })
+            }));
+            assert!(result.is_err());
+            assert_each_once(arr, &drops);
+        }
+    }
+}
@@ -0,0 +1,266 @@
+//! 桶排序 (Bucket Sort)
+//!
+//! # 算法原理
+//! 桶排序将数据分到有限数量的桶中：
+//! 1. 根据数据范围创建若干个桶
+//! 2. 将每个元素放入对应的桶中
+//! 3. 对每个非空桶进行排序
+//! 4. 按顺序合并所有桶中的元素
+//!
+//! # 时间复杂度
+//! - 最好/平均: O(n + k)，k是桶的数量
+//! - 最坏: O(n²)，当所有元素都在同一个桶中
+//!
+//! # 空间复杂度: O(n + k)
+//! # 稳定性: 取决于桶内排序算法
+//!
+//! 浮点数桶排序按 `num * n` 计算桶号，只适用于 [0, 1) 区间；
+//! 区间外的值或 NaN 会落进错误的桶，所以排序前先检查输入。
+
+use crate::{Order, SortError};
+
+/// 浮点数桶排序支持的区间
+const UNIT_INTERVAL: &str = "[0, 1)";
+
+/// 桶排序 - 适用于 [0, 1) 范围的浮点数
+///
+/// # Panics
+/// 输入包含 NaN 或 [0, 1) 之外的值时 panic；需要自己处理时使用 [`try_bucket_sort_float`]
+pub fn bucket_sort_float(arr: &mut [f64]) {
+    bucket_sort_float_with_order(arr, Order::Ascending);
+}
+
+/// 按指定方向排序的浮点数桶排序
+///
+/// 降序时桶号取镜像（最大的值落在第一个桶），桶内也按降序插入排序，
+/// 所以相等元素的相对顺序不变。
+///
+/// # Panics
+/// 输入包含 NaN 或 [0, 1) 之外的值时 panic
+pub fn bucket_sort_float_with_order(arr: &mut [f64], order: Order) {
+    if let Err(err) = try_bucket_sort_float(arr, order) {
+        panic!("{}", err);
+    }
+}
+
+/// 带输入检查的浮点数桶排序
+///
+/// 输入包含 NaN 时返回 [`SortError::NotANumber`]，包含 [0, 1) 之外的值时返回
+/// [`SortError::OutOfDomain`]，两种情况都不修改数组。
+pub fn try_bucket_sort_float(arr: &mut [f64], order: Order) -> Result<(), SortError> {
+    // 先检查再排序，出错时数组保持原样
+    for (index, &num) in arr.iter().enumerate() {
+        if num.is_nan() {
+            return Err(SortError::NotANumber { index });
+        }
+        if !(0.0..1.0).contains(&num) {
+            return Err(SortError::OutOfDomain {
+                index,
+                domain: UNIT_INTERVAL,
+            });
+        }
+    }
+
+    let n = arr.len();
+    if n <= 1 {
+        return Ok(());
+    }
+
+    // 创建n个空桶
+    let mut buckets: Vec<Vec<f64>> = vec![Vec::new(); n];
+
+    // 将元素分配到桶中
+    for &num in arr.iter() {
+        // 计算桶索引：num * n
+        let bucket_idx = (num * n as f64) as usize;
+        // 确保索引不越界
+        let idx = mirror(bucket_idx.min(n - 1), n, order);
+        buckets[idx].push(num);
+    }
+
+    // 对每个桶进行排序（使用插入排序，适合小数组）
+    for bucket in buckets.iter_mut() {
+        insertion_sort(bucket, order);
+    }
+
+    // 合并所有桶
+    let mut index = 0;
+    for bucket in buckets {
+        for num in bucket {
+            arr[index] = num;
+            index += 1;
+        }
+    }
+    Ok(())
+}
+
+/// 桶排序 - 适用于整数
+pub fn bucket_sort_int(arr: &mut [i32]) {
+    bucket_sort_int_with_order(arr, Order::Ascending);
+}
+
+/// 按指定方向排序的整数桶排序
+pub fn bucket_sort_int_with_order(arr: &mut [i32], order: Order) {
+    let n = arr.len();
+    if n <= 1 {
+        return;
+    }
+
+    // 找到最大值和最小值
+    let min_val = *arr.iter().min().unwrap();
+    let max_val = *arr.iter().max().unwrap();
+
+    if min_val == max_val {
+        return;
+    }
+
+    // 计算桶的数量和每个桶的范围
+    let bucket_count = n;
+    // 在 i64 中计算差值，i32::MIN..=i32::MAX 的跨度也不会溢出
+    let range = (max_val as i64 - min_val as i64) as f64;
+
+    // 创建桶
+    let mut buckets: Vec<Vec<i32>> = vec![Vec::new(); bucket_count];
+
+    // 分配元素到桶
+    for &num in arr.iter() {
+        let bucket_idx =
+            (((num as i64 - min_val as i64) as f64 / range) * (bucket_count - 1) as f64) as usize;
+        buckets[mirror(bucket_idx, bucket_count, order)].push(num);
+    }
+
+    // 对每个桶排序并合并
+    let mut index = 0;
+    for bucket in buckets.iter_mut() {
+        bucket.sort_by(|a, b| order.apply(a.cmp(b))); // 使用标准库的稳定排序
+        for &num in bucket.iter() {
+            arr[index] = num;
+            index += 1;
+        }
+    }
+}
+
+/// 降序时把桶号镜像到另一端
+fn mirror(bucket_idx: usize, bucket_count: usize, order: Order) -> usize {
+    match order {
+        Order::Ascending => bucket_idx,
+        Order::Descending => bucket_count - 1 - bucket_idx,
+    }
+}
+
+/// 插入排序（用于桶内排序）
+fn insertion_sort<T: PartialOrd + Clone>(arr: &mut [T], order: Order) {
+    for i in 1..arr.len() {
+        let key = arr[i].clone();
+        let mut j = i;
+        while j > 0 && out_of_order(&arr[j - 1], &key, order) {
+            arr[j] = arr[j - 1].clone();
+            j -= 1;
+        }
+        arr[j] = key;
+    }
+}
+
+/// `prev` 是否应该排在 `next` 之后
+fn out_of_order<T: PartialOrd>(prev: &T, next: &T, order: Order) -> bool {
+    match order {
+        Order::Ascending => prev > next,
+        Order::Descending => prev < next,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bucket_sort_float() {
+        let mut arr = vec![0.78, 0.17, 0.39, 0.26, 0.72];
+        bucket_sort_float(&mut arr);
+        assert_eq!(arr, vec![0.17, 0.26, 0.39, 0.72, 0.78]);
+    }
+
+    #[test]
+    fn test_bucket_sort_int() {
+        let mut arr = vec![29, 25, 3, 49, 9, 37, 21, 43];
+        bucket_sort_int(&mut arr);
+        assert_eq!(arr, vec![3, 9, 21, 25, 29, 37, 43, 49]);
+    }
+
+    #[test]
+    fn test_empty() {
+        let mut arr: Vec<f64> = vec![];
+        bucket_sort_float(&mut arr);
+        assert_eq!(arr, vec![]);
+    }
+
+    #[test]
+    fn test_single() {
+        let mut arr = vec![0.5];
+        bucket_sort_float(&mut arr);
+        assert_eq!(arr, vec![0.5]);
+    }
+
+    #[test]
+    fn test_duplicates() {
+        let mut arr = vec![0.5, 0.3, 0.5, 0.3, 0.5];
+        bucket_sort_float(&mut arr);
+        assert_eq!(arr, vec![0.3, 0.3, 0.5, 0.5, 0.5]);
+    }
+
+    #[test]
+    fn test_descending() {
+        let mut arr = vec![0.78, 0.17, 0.39, 0.26, 0.72, 0.17];
+        bucket_sort_float_with_order(&mut arr, Order::Descending);
+        assert_eq!(arr, vec![0.78, 0.72, 0.39, 0.26, 0.17, 0.17]);
+
+        let mut arr = vec![29, 25, 3, 49, 9, 37, 21, 43];
+        bucket_sort_int_with_order(&mut arr, Order::Descending);
+        assert_eq!(arr, vec![49, 43, 37, 29, 25, 21, 9, 3]);
+    }
+
+    #[test]
+    fn test_try_bucket_sort_float_rejects_invalid_input() {
+        let mut arr = vec![0.5, f64::NAN, 0.1];
+        assert_eq!(
+            try_bucket_sort_float(&mut arr, Order::Ascending),
+            Err(SortError::NotANumber { index: 1 })
+        );
+
+        // 负数和 >= 1 的值以前会被静默放进第一个或最后一个桶
+        let mut arr = vec![0.5, 0.2, -0.3, 0.1];
+        assert_eq!(
+            try_bucket_sort_float(&mut arr, Order::Ascending),
+            Err(SortError::OutOfDomain {
+                index: 2,
+                domain: "[0, 1)"
+            })
+        );
+        assert_eq!(arr, vec![0.5, 0.2, -0.3, 0.1]);
+
+        let mut arr = vec![1.0, 0.5];
+        assert!(try_bucket_sort_float(&mut arr, Order::Descending).is_err());
+
+        let mut arr = vec![0.9, 0.0, 0.4];
+        assert_eq!(try_bucket_sort_float(&mut arr, Order::Ascending), Ok(()));
+        assert_eq!(arr, vec![0.0, 0.4, 0.9]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_bucket_sort_float_panics_out_of_domain() {
+        bucket_sort_float(&mut [0.5, 2.0]);
+    }
+
+    #[test]
+    fn test_bucket_sort_int_full_range() {
+        // max_val - min_val 在 i32 中会溢出
+        let mut arr = vec![i32::MAX, 0, i32::MIN, -1, i32::MAX, 1];
+        bucket_sort_int(&mut arr);
+        assert_eq!(arr, vec![i32::MIN, -1, 0, 1, i32::MAX, i32::MAX]);
+
+        let mut arr = vec![i32::MIN, i32::MAX, 0];
+        bucket_sort_int_with_order(&mut arr, Order::Descending);
+        assert_eq!(arr, vec![i32::MAX, 0, i32::MIN]);
+    }
+}
@@ -0,0 +1,113 @@
+//! 缓存键排序 (Schwartzian Transform)
+//!
+//! # 算法原理
+//! 当排序键的计算代价较高时（解析日期、转小写字符串等），
+//! `xxx_by_key` 在每次比较时都要重新计算两次键。缓存键排序只计算一次：
+//! 1. 为每个元素计算键，得到 `(key, index)` 数组
+//! 2. 用指定的排序算法对 `(key, index)` 排序
+//! 3. 按排序后的下标原地置换原数组
+//!
+//! 由于下标参与比较，键相同的元素按原有顺序排列，
+//! 所以即使使用快速排序、堆排序这类不稳定算法，结果也是稳定的。
+//!
+//! # 时间复杂度: 排序算法本身的复杂度 + O(n) 次键计算
+//! # 空间复杂度: O(n) - 存储 `(key, index)` 数组
+
+/// 使用指定排序算法的缓存键排序
+///
+/// `key` 对每个元素只调用一次；`sort` 负责对 `(key, index)` 数组升序排序。
+///
+/// # 示例
+/// ```
+/// use sort::cached_key::sort_by_cached_key;
+/// use sort::heap_sort::heap_sort;
+///
+/// let mut words = vec!["Banana", "apple", "Cherry", "APPLE"];
+/// sort_by_cached_key(&mut words, |w| w.to_lowercase(), heap_sort);
+/// assert_eq!(words, vec!["apple", "APPLE", "Banana", "Cherry"]);
+/// ```
+pub fn sort_by_cached_key<T, K, F, S>(arr: &mut [T], key: F, sort: S)
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+    S: FnOnce(&mut [(K, usize)]),
+{
+    if arr.len() <= 1 {
+        return;
+    }
+
+    // 每个元素只计算一次键，下标用于之后的置换和保证稳定性
+    let mut indices: Vec<(K, usize)> = arr
+        .iter()
+        .map(key)
+        .enumerate()
+        .map(|(i, k)| (k, i))
+        .collect();
+
+    sort(&mut indices);
+
+    apply_permutation(arr, &mut indices);
+}
+
+/// 按 `indices` 中记录的原始下标原地置换数组
+///
+/// 排序后第 i 个位置应放原数组中下标为 `indices[i].1` 的元素。
+/// 前面的位置已经被交换过，所以要沿着下标链找到元素的当前位置。
+pub(crate) fn apply_permutation<T, K>(arr: &mut [T], indices: &mut [(K, usize)]) {
+    for i in 0..arr.len() {
+        let mut index = indices[i].1;
+        while index < i {
+            index = indices[index].1;
+        }
+        indices[i].1 = index;
+        arr.swap(i, index);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::heap_sort::heap_sort;
+    use crate::merge_sort::merge_sort;
+    use crate::quick_sort::quick_sort;
+
+    #[test]
+    fn test_sort_by_cached_key() {
+        let mut arr = vec![-5, 3, -1, 0, 2, -4];
+        sort_by_cached_key(&mut arr, |x: &i32| x.abs(), merge_sort);
+        assert_eq!(arr, vec![0, -1, 2, 3, -4, -5]);
+    }
+
+    #[test]
+    fn test_key_computed_once() {
+        let mut calls = 0;
+        let mut arr = vec![5, 1, 4, 2, 3, 9, 7, 8, 6, 0];
+        sort_by_cached_key(
+            &mut arr,
+            |x| {
+                calls += 1;
+                *x
+            },
+            quick_sort,
+        );
+        assert_eq!(arr, vec![0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+        assert_eq!(calls, 10);
+    }
+
+    #[test]
+    fn test_stable_with_unstable_algorithm() {
+        let mut arr = vec![(2, 'a'), (1, 'b'), (2, 'c'), (1, 'd'), (2, 'e'), (1, 'f')];
+        sort_by_cached_key(&mut arr, |p| p.0, heap_sort);
+        assert_eq!(
+            arr,
+            vec![(1, 'b'), (1, 'd'), (1, 'f'), (2, 'a'), (2, 'c'), (2, 'e')]
+        );
+    }
+
+    #[test]
+    fn test_empty() {
+        let mut arr: Vec<i32> = vec![];
+        sort_by_cached_key(&mut arr, |x| *x, quick_sort);
+        assert_eq!(arr, vec![]);
+    }
+}
@@ -0,0 +1,446 @@
+//! 计数排序 (Counting Sort)
+//!
+//! # 算法原理
+//! 计数排序是一种非比较排序算法：
+//! 1. 找出待排序数组中的最大值和最小值
+//! 2. 创建计数数组，统计每个值出现的次数
+//! 3. 累加计数，得到每个值在输出数组中的位置
+//! 4. 反向遍历原数组，将元素放到正确位置
+//!
+//! 任何能映射到一个较小整数值域的键都可以使用计数排序，见 [`CountingKey`]。
+//! 计数数组的长度是 `max - min + 1`，值域过大时会占用大量内存，
+//! 所以先检查值域，超过上限时返回 [`SortError::RangeTooLarge`]，而不是溢出或耗尽内存。
+//!
+//! # 时间复杂度: O(n + k)，其中k是数据范围
+//! # 空间复杂度: O(n + k)
+//! # 稳定性: 稳定（使用反向遍历时）
+//!
+//! # 适用场景: 数据范围不大的整数排序
+
+use crate::cached_key::apply_permutation;
+use crate::{Order, SortError};
+
+/// 默认允许的最大值域（计数数组长度），约占用 128 MB 内存
+pub const DEFAULT_MAX_RANGE: usize = 1 << 24;
+
+/// 可以用于计数排序的键
+///
+/// `ordinal` 把键映射为一个 `u128` 序号，序号的大小关系必须与键的顺序一致。
+/// 计数排序只关心序号的值域 `max - min`，所以序号本身可以很大。
+///
+/// 也可以为自己的类型实现，例如把枚举映射为它的序号：
+///
+/// ```
+/// use sort::counting_sort::try_counting_sort;
+/// use sort::{CountingKey, Order};
+///
+/// #[derive(Debug, Clone, Copy, PartialEq)]
+/// enum Level {
+///     Low,
+///     Mid,
+///     High,
+/// }
+///
+/// impl CountingKey for Level {
+///     fn ordinal(&self) -> u128 {
+///         *self as u128
+///     }
+/// }
+///
+/// let mut levels = vec![Level::High, Level::Low, Level::Mid, Level::Low];
+/// try_counting_sort(&mut levels, 16, Order::Ascending).unwrap();
+/// assert_eq!(levels, vec![Level::Low, Level::Low, Level::Mid, Level::High]);
+/// ```
+pub trait CountingKey: Copy {
+    /// 保持顺序的整数序号
+    fn ordinal(&self) -> u128;
+}
+
+macro_rules! impl_counting_key_unsigned {
+    ($($t:ty),*) => {
+        $(
+            impl CountingKey for $t {
+                #[inline]
+                fn ordinal(&self) -> u128 {
+                    *self as u128
+                }
+            }
+        )*
+    };
+}
+
+impl_counting_key_unsigned!(u8, u16, u32, u64, u128, usize);
+
+macro_rules! impl_counting_key_signed {
+    ($($t:ty => $u:ty),*) => {
+        $(
+            impl CountingKey for $t {
+                #[inline]
+                fn ordinal(&self) -> u128 {
+                    // 翻转符号位：MIN 映射为 0，MAX 映射为无符号最大值
+                    ((*self as $u) ^ (1 << (<$u>::BITS - 1))) as u128
+                }
+            }
+        )*
+    };
+}
+
+impl_counting_key_signed!(i8 => u8, i16 => u16, i32 => u32, i64 => u64, i128 => u128, isize => usize);
+
+impl CountingKey for bool {
+    #[inline]
+    fn ordinal(&self) -> u128 {
+        *self as u128
+    }
+}
+
+impl CountingKey for char {
+    #[inline]
+    fn ordinal(&self) -> u128 {
+        *self as u128
+    }
+}
+
+/// 计数排序
+///
+/// # Panics
+/// 值域超过 [`DEFAULT_MAX_RANGE`] 时 panic；需要自己处理时使用 [`try_counting_sort`]
+pub fn counting_sort<K: CountingKey>(arr: &mut [K]) {
+    counting_sort_with_order(arr, Order::Ascending);
+}
+
+/// 按指定方向排序的计数排序
+///
+/// # Panics
+/// 值域超过 [`DEFAULT_MAX_RANGE`] 时 panic
+pub fn counting_sort_with_order<K: CountingKey>(arr: &mut [K], order: Order) {
+    if let Err(err) = try_counting_sort(arr, DEFAULT_MAX_RANGE, order) {
+        panic!("{}", err);
+    }
+}
+
+/// 稳定版计数排序，与 [`counting_sort`] 相同
+///
+/// 计数排序统一使用反向遍历的稳定实现，保留这个名字是为了兼容。
+///
+/// # Panics
+/// 值域超过 [`DEFAULT_MAX_RANGE`] 时 panic
+pub fn counting_sort_stable<K: CountingKey>(arr: &mut [K]) {
+    counting_sort_with_order(arr, Order::Ascending);
+}
+
+/// 按指定方向排序的稳定版计数排序
+///
+/// 降序时把值映射为 `max - num`，最大值占据计数数组的第一个位置，
+/// 其余步骤与升序完全相同，所以相等元素依然保持原有顺序。
+///
+/// # Panics
+/// 值域超过 [`DEFAULT_MAX_RANGE`] 时 panic
+pub fn counting_sort_stable_with_order<K: CountingKey>(arr: &mut [K], order: Order) {
+    counting_sort_with_order(arr, order);
+}
+
+/// 带值域检查的计数排序
+///
+/// 值域 `max - min + 1` 超过 `max_range` 时不做任何修改，返回 [`SortError::RangeTooLarge`]。
+pub fn try_counting_sort<K: CountingKey>(
+    arr: &mut [K],
+    max_range: usize,
+    order: Order,
+) -> Result<(), SortError> {
+    if arr.len() <= 1 {
+        return Ok(());
+    }
+
+    // 找到最小和最大序号，检查值域
+    let bounds = KeyBounds::new(arr.iter().copied(), max_range)?;
+
+    // 创建计数数组
+    let mut count = vec![0usize; bounds.len()];
+
+    // 统计每个值的出现次数
+    for key in arr.iter() {
+        count[bounds.slot(key, order)] += 1;
+    }
+
+    // 累加计数，得到每个值的结束位置
+    for i in 1..count.len() {
+        count[i] += count[i - 1];
+    }
+
+    // 创建输出数组，反向遍历保证稳定性
+    let mut output = arr.to_vec();
+    for key in arr.iter().rev() {
+        let idx = bounds.slot(key, order);
+        count[idx] -= 1;
+        output[count[idx]] = *key;
+    }
+
+    // 复制回原数组
+    arr.copy_from_slice(&output);
+    Ok(())
+}
+
+/// 按键排序的稳定计数排序：用 `key` 从元素中取出整数键，移动整个元素
+///
+/// 每个元素的键只计算一次，先按计数排序算出 `(key, index)` 的最终位置，
+/// 再按下标原地置换原数组，所以元素不需要实现 `Copy` 或 `Clone`。
+/// 键相同的元素保持原有顺序。
+///
+/// # 示例
+/// ```
+/// use sort::counting_sort::counting_sort_by_key;
+///
+/// let mut scores = vec![("Bob", 90), ("Amy", 85), ("Cat", 90), ("Dan", 70)];
+/// counting_sort_by_key(&mut scores, |s| s.1);
+/// assert_eq!(scores, vec![("Dan", 70), ("Amy", 85), ("Bob", 90), ("Cat", 90)]);
+/// ```
+///
+/// # Panics
+/// 键的值域超过 [`DEFAULT_MAX_RANGE`] 时 panic
+pub fn counting_sort_by_key<T, K, F>(arr: &mut [T], key: F)
+where
+    K: CountingKey,
+    F: FnMut(&T) -> K,
+{
+    counting_sort_by_key_with_order(arr, key, Order::Ascending);
+}
+
+/// 按指定方向、按键排序的稳定计数排序
+///
+/// # Panics
+/// 键的值域超过 [`DEFAULT_MAX_RANGE`] 时 panic
+pub fn counting_sort_by_key_with_order<T, K, F>(arr: &mut [T], key: F, order: Order)
+where
+    K: CountingKey,
+    F: FnMut(&T) -> K,
+{
+    if let Err(err) = try_counting_sort_by_key(arr, key, DEFAULT_MAX_RANGE, order) {
+        panic!("{}", err);
+    }
+}
+
+/// 带值域检查、按键排序的稳定计数排序
+///
+/// 键的值域超过 `max_range` 时不做任何修改，返回 [`SortError::RangeTooLarge`]。
+pub fn try_counting_sort_by_key<T, K, F>(
+    arr: &mut [T],
+    key: F,
+    max_range: usize,
+    order: Order,
+) -> Result<(), SortError>
+where
+    K: CountingKey,
+    F: FnMut(&T) -> K,
+{
+    if arr.len() <= 1 {
+        return Ok(());
+    }
+
+    let keys: Vec<K> = arr.iter().map(key).collect();
+    let bounds = KeyBounds::new(keys.iter().copied(), max_range)?;
+
+    let mut count = vec![0usize; bounds.len()];
+    for k in keys.iter() {
+        count[bounds.slot(k, order)] += 1;
+    }
+
+    // 计数转换为每个键的起始位置
+    let mut offset = 0;
+    for c in count.iter_mut() {
+        let cnt = *c;
+        *c = offset;
+        offset += cnt;
+    }
+
+    // 正向遍历，indices[pos] 记录最终位置 pos 上元素的原始下标
+    let mut indices = vec![(0usize, 0usize); arr.len()];
+    for (i, k) in keys.iter().enumerate() {
+        let s = bounds.slot(k, order);
+        indices[count[s]] = (s, i);
+        count[s] += 1;
+    }
+
+    apply_permutation(arr, &mut indices);
+    Ok(())
+}
+
+/// 键序号的最小值和最大值，已确认值域不超过上限
+struct KeyBounds {
+    min: u128,
+    max: u128,
+}
+
+impl KeyBounds {
+    fn new<K: CountingKey>(
+        keys: impl Iterator<Item = K>,
+        max_range: usize,
+    ) -> Result<Self, SortError> {
+        let mut min = u128::MAX;
+        let mut max = u128::MIN;
+        for key in keys {
+            let ordinal = key.ordinal();
+            min = min.min(ordinal);
+            max = max.max(ordinal);
+        }
+
+        // max - min 不会溢出；计数数组长度是 max - min + 1
+        let range = max - min;
+        if range >= max_range as u128 {
+            return Err(SortError::RangeTooLarge {
+                range: range.saturating_add(1),
+                max_range,
+            });
+        }
+        Ok(KeyBounds { min, max })
+    }
+
+    /// 计数数组长度
+    fn len(&self) -> usize {
+        (self.max - self.min) as usize + 1
+    }
+
+    /// 键在计数数组中的位置，降序时取镜像
+    fn slot<K: CountingKey>(&self, key: &K, order: Order) -> usize {
+        let ordinal = key.ordinal();
+        match order {
+            Order::Ascending => (ordinal - self.min) as usize,
+            Order::Descending => (self.max - ordinal) as usize,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_counting_sort() {
+        let mut arr: Vec<usize> = vec![4, 2, 2, 8, 3, 3, 1];
+        counting_sort(&mut arr);
+        assert_eq!(arr, vec![1, 2, 2, 3, 3, 4, 8]);
+    }
+
+    #[test]
+    fn test_counting_sort_stable() {
+        let mut arr = vec![-5, 3, -1, 0, 3, -5, 2];
+        counting_sort_stable(&mut arr);
+        assert_eq!(arr, vec![-5, -5, -1, 0, 2, 3, 3]);
+    }
+
+    #[test]
+    fn test_empty() {
+        let mut arr: Vec<usize> = vec![];
+        counting_sort(&mut arr);
+        assert_eq!(arr, vec![]);
+    }
+
+    #[test]
+    fn test_single() {
+        let mut arr: Vec<usize> = vec![42];
+        counting_sort(&mut arr);
+        assert_eq!(arr, vec![42]);
+    }
+
+    #[test]
+    fn test_all_same() {
+        let mut arr: Vec<usize> = vec![5, 5, 5, 5];
+        counting_sort(&mut arr);
+        assert_eq!(arr, vec![5, 5, 5, 5]);
+    }
+
+    #[test]
+    fn test_descending() {
+        let mut arr: Vec<usize> = vec![4, 2, 2, 8, 3, 3, 1];
+        counting_sort_with_order(&mut arr, Order::Descending);
+        assert_eq!(arr, vec![8, 4, 3, 3, 2, 2, 1]);
+
+        let mut arr = vec![-5, 3, -1, 0, 3, -5, 2];
+        counting_sort_stable_with_order(&mut arr, Order::Descending);
+        assert_eq!(arr, vec![3, 3, 2, 0, -1, -5, -5]);
+    }
+
+    #[test]
+    fn test_counting_sort_by_key_is_stable() {
+        let mut rows = vec![
+            (3, String::from("c1")),
+            (1, String::from("a1")),
+            (3, String::from("c2")),
+            (2, String::from("b1")),
+            (1, String::from("a2")),
+        ];
+        counting_sort_by_key(&mut rows, |r| r.0);
+        let tags: Vec<&str> = rows.iter().map(|r| r.1.as_str()).collect();
+        assert_eq!(tags, vec!["a1", "a2", "b1", "c1", "c2"]);
+
+        counting_sort_by_key_with_order(&mut rows, |r| r.0, Order::Descending);
+        let tags: Vec<&str> = rows.iter().map(|r| r.1.as_str()).collect();
+        assert_eq!(tags, vec!["c1", "c2", "b1", "a1", "a2"]);
+    }
+
+    #[test]
+    fn test_generic_keys() {
+        let mut arr: Vec<i64> = vec![i64::MAX, i64::MAX - 2, i64::MAX - 1, i64::MAX - 2];
+        counting_sort(&mut arr);
+        assert_eq!(
+            arr,
+            vec![i64::MAX - 2, i64::MAX - 2, i64::MAX - 1, i64::MAX]
+        );
+
+        let mut arr = vec!['c', 'a', 'd', 'b'];
+        counting_sort_with_order(&mut arr, Order::Descending);
+        assert_eq!(arr, vec!['d', 'c', 'b', 'a']);
+
+        let mut arr: Vec<i8> = vec![127, -128, 0, -1];
+        counting_sort_stable(&mut arr);
+        assert_eq!(arr, vec![-128, -1, 0, 127]);
+    }
+
+    #[test]
+    fn test_range_too_large() {
+        // 跨越整个 i32 范围：以前计算 max - min + 1 时会溢出
+        let mut arr = vec![i32::MAX, 0, i32::MIN];
+        let err = try_counting_sort(&mut arr, DEFAULT_MAX_RANGE, Order::Ascending).unwrap_err();
+        assert_eq!(
+            err,
+            SortError::RangeTooLarge {
+                range: 1 << 32,
+                max_range: DEFAULT_MAX_RANGE
+            }
+        );
+        assert_eq!(arr, vec![i32::MAX, 0, i32::MIN]);
+
+        // 单个很大的值：以前会尝试分配 max_val + 1 个计数器
+        let mut arr: Vec<u64> = vec![4_000_000_000, 1, 2];
+        assert!(try_counting_sort(&mut arr, DEFAULT_MAX_RANGE, Order::Ascending).is_err());
+
+        // u128 全范围也不会溢出
+        let mut arr = vec![u128::MAX, 0];
+        let err = try_counting_sort(&mut arr, 1000, Order::Ascending).unwrap_err();
+        assert_eq!(
+            err,
+            SortError::RangeTooLarge {
+                range: u128::MAX,
+                max_range: 1000
+            }
+        );
+    }
+
+    #[test]
+    fn test_configurable_max_range() {
+        let mut arr: Vec<u32> = vec![10, 19, 15];
+        assert!(try_counting_sort(&mut arr, 9, Order::Ascending).is_err());
+        assert!(try_counting_sort(&mut arr, 10, Order::Ascending).is_ok());
+        assert_eq!(arr, vec![10, 15, 19]);
+
+        let mut rows = vec![(1_000_000u64, "a"), (0, "b")];
+        assert!(try_counting_sort_by_key(&mut rows, |r| r.0, 1000, Order::Ascending).is_err());
+        assert_eq!(rows, vec![(1_000_000, "a"), (0, "b")]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_panics_on_huge_range() {
+        counting_sort_stable(&mut [i32::MIN, i32::MAX]);
+    }
+}
@@ -0,0 +1,57 @@
+//! 排序错误类型
+
+use std::fmt;
+
+/// 非比较排序在输入不满足前提条件时返回的错误
+///
+/// 带 `try_` 前缀的函数在返回错误时不会修改输入数组。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SortError {
+    /// 键的值域 `max - min + 1` 超过允许的计数数组长度
+    RangeTooLarge {
+        /// 实际需要的计数数组长度（超过 `u128` 时取 `u128::MAX`）
+        range: u128,
+        /// 允许的最大长度
+        max_range: usize,
+    },
+    /// 第 `index` 个元素是 NaN，无法确定它的位置
+    NotANumber {
+        /// 元素下标
+        index: usize,
+    },
+    /// 第 `index` 个元素不在算法支持的区间内
+    OutOfDomain {
+        /// 元素下标
+        index: usize,
+        /// 支持的区间，例如 `"[0, 1)"`
+        domain: &'static str,
+    },
+    /// 基数排序每趟处理的位数不在 `1..=max` 之内
+    InvalidRadixBits {
+        /// 传入的位数
+        bits: u32,
+        /// 允许的最大位数
+        max: u32,
+    },
+}
+
+impl fmt::Display for SortError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SortError::RangeTooLarge { range, max_range } => write!(
+                f,
+                "值域过大：需要 {} 个计数器，最多允许 {} 个",
+                range, max_range
+            ),
+            SortError::NotANumber { index } => write!(f, "第 {} 个元素是 NaN", index),
+            SortError::OutOfDomain { index, domain } => {
+                write!(f, "第 {} 个元素不在 {} 区间内", index, domain)
+            }
+            SortError::InvalidRadixBits { bits, max } => {
+                write!(f, "radix_bits 必须在 1..={} 之间，实际为 {}", max, bits)
+            }
+        }
+    }
+}
+
+impl std::error::Error for SortError {}
@@ -0,0 +1,488 @@
+//! 堆排序 (Heap Sort)
+//!
+//! # 算法原理
+//! 堆排序利用堆这种数据结构进行排序：
+//! 1. 将数组构建成最大堆
+//! 2. 将堆顶（最大值）与末尾元素交换
+//! 3. 缩小堆的范围，重新调整堆
+//! 4. 重复步骤2-3直到堆大小为1
+//!
+//! 自底向上堆排序（Floyd）取出堆顶后先沿着较大的子节点走到叶子，再往上找到末尾元素的位置，
+//! 比较次数约为普通堆排序的一半；d 叉堆更矮，大数组上的缓存表现更好。
+//!
+//! # 时间复杂度: O(n log n) - 所有情况
+//! # 空间复杂度: O(1) - 原地排序
+//! # 稳定性: 不稳定
+
+use crate::cached_key::sort_by_cached_key;
+use crate::Order;
+use std::cmp::Ordering;
+
+/// 堆排序主函数
+pub fn heap_sort<T: Ord>(arr: &mut [T]) {
+    heap_sort_by(arr, T::cmp);
+}
+
+/// 按指定方向排序的堆排序
+pub fn heap_sort_with_order<T: Ord>(arr: &mut [T], order: Order) {
+    heap_sort_by(arr, |a, b| order.apply(a.cmp(b)));
+}
+
+/// 使用自定义比较函数的堆排序
+///
+/// 按 `compare` 构建最大堆，结果按 `compare` 升序排列。
+pub fn heap_sort_by<T, F>(arr: &mut [T], mut compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let n = arr.len();
+    if n <= 1 {
+        return;
+    }
+
+    // 第一步：构建最大堆
+    // 从最后一个非叶子节点开始，自底向上调整
+    // 最后一个非叶子节点的索引是 n/2 - 1
+    for i in (0..n / 2).rev() {
+        heapify_by(arr, n, i, &mut compare);
+    }
+
+    // 第二步：逐个取出堆顶元素
+    for i in (1..n).rev() {
+        // 将堆顶（最大值）与当前末尾交换
+        arr.swap(0, i);
+        // 对剩余元素重新调整堆
+        heapify_by(arr, i, 0, &mut compare);
+    }
+}
+
+/// 按键排序的堆排序，比较 `key(a)` 与 `key(b)`
+///
+/// 每次比较都会重新计算键，键的计算代价较高时改用 [`heap_sort_by_cached_key`]。
+pub fn heap_sort_by_key<T, K, F>(arr: &mut [T], mut key: F)
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    heap_sort_by(arr, |a, b| key(a).cmp(&key(b)));
+}
+
+/// 缓存键的堆排序：每个元素的键只计算一次，结果是稳定的
+pub fn heap_sort_by_cached_key<T, K, F>(arr: &mut [T], key: F)
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    sort_by_cached_key(arr, key, heap_sort);
+}
+
+/// 堆调整函数（下沉操作）
+/// 确保以 root 为根的子树满足最大堆性质
+///
+/// # 参数
+/// - `arr`: 数组
+/// - `heap_size`: 堆的大小（数组中参与堆操作的元素数量）
+/// - `root`: 需要调整的根节点索引
+pub fn heapify<T: Ord>(arr: &mut [T], heap_size: usize, root: usize) {
+    heapify_by(arr, heap_size, root, T::cmp);
+}
+
+/// 使用自定义比较函数的堆调整
+pub fn heapify_by<T, F>(arr: &mut [T], heap_size: usize, root: usize, mut compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    heapify_recursive(arr, heap_size, root, &mut compare);
+}
+
+fn heapify_recursive<T, F>(arr: &mut [T], heap_size: usize, root: usize, compare: &mut F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let mut largest = root; // 假设根节点最大
+    let left = 2 * root + 1; // 左子节点
+    let right = 2 * root + 2; // 右子节点
+
+    // 如果左子节点比根节点大
+    if left < heap_size && compare(&arr[left], &arr[largest]).is_gt() {
+        largest = left;
+    }
+
+    // 如果右子节点比当前最大的还大
+    if right < heap_size && compare(&arr[right], &arr[largest]).is_gt() {
+        largest = right;
+    }
+
+    // 如果最大的不是根节点，需要交换并继续调整
+    if largest != root {
+        arr.swap(root, largest);
+        // 递归调整被影响的子树
+        heapify_recursive(arr, heap_size, largest, compare);
+    }
+}
+
+/// 迭代版本的堆调整（避免递归栈溢出）
+pub fn heapify_iterative<T: Ord>(arr: &mut [T], heap_size: usize, root: usize) {
+    heapify_iterative_by(arr, heap_size, root, T::cmp);
+}
+
+/// 使用自定义比较函数的迭代版堆调整
+pub fn heapify_iterative_by<T, F>(arr: &mut [T], heap_size: usize, root: usize, mut compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    sift_down::<2, T, F>(arr, heap_size, root, &mut compare);
+}
+
+/// `D` 叉最大堆的下沉操作，节点 `i` 的子节点是 `D * i + 1 ..= D * i + D`
+///
+/// 每层先在子节点中找出最大的一个（`D - 1` 次比较），再与根比较一次。
+fn sift_down<const D: usize, T, F>(
+    arr: &mut [T],
+    heap_size: usize,
+    mut root: usize,
+    compare: &mut F,
+) where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    loop {
+        let first = D * root + 1;
+        if first >= heap_size {
+            break;
+        }
+
+        let largest = largest_child::<D, T, F>(arr, heap_size, first, compare);
+        if !compare(&arr[largest], &arr[root]).is_gt() {
+            break;
+        }
+
+        arr.swap(root, largest);
+        root = largest;
+    }
+}
+
+/// 在从 `first` 开始的至多 `D` 个子节点中找出最大的一个
+fn largest_child<const D: usize, T, F>(
+    arr: &[T],
+    heap_size: usize,
+    first: usize,
+    compare: &mut F,
+) -> usize
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let mut largest = first;
+    for child in first + 1..(first + D).min(heap_size) {
+        if compare(&arr[child], &arr[largest]).is_gt() {
+            largest = child;
+        }
+    }
+    largest
+}
+
+/// 自底向上（Floyd）的下沉操作
+///
+/// 堆顶换成末尾元素后，它通常很小，最终会沉到接近叶子的位置。
+/// 普通的下沉每层都要把它与子节点比较；这里先不管它，沿着最大的子节点一直走到叶子，
+/// 再从叶子往上找到它应在的位置，往上找通常只需要一两步。
+fn sift_down_bottom_up<const D: usize, T, F>(
+    arr: &mut [T],
+    heap_size: usize,
+    root: usize,
+    compare: &mut F,
+) where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    // 沿着最大的子节点走到叶子
+    let mut pos = root;
+    loop {
+        let first = D * pos + 1;
+        if first >= heap_size {
+            break;
+        }
+        pos = largest_child::<D, T, F>(arr, heap_size, first, compare);
+    }
+
+    // 往上找到第一个不小于根元素的节点，根元素应该放在这里
+    while pos != root && compare(&arr[root], &arr[pos]).is_gt() {
+        pos = (pos - 1) / D;
+    }
+
+    // 根元素放到 pos，路径上 root 到 pos 之间的元素各上移一层：
+    // 从 pos 开始依次与根交换，根位置上的元素逐层往下传递
+    while pos != root {
+        arr.swap(root, pos);
+        pos = (pos - 1) / D;
+    }
+}
+
+/// 使用迭代版本的堆排序
+pub fn heap_sort_iterative<T: Ord>(arr: &mut [T]) {
+    heap_sort_iterative_by(arr, T::cmp);
+}
+
+/// 按指定方向排序的迭代版堆排序
+pub fn heap_sort_iterative_with_order<T: Ord>(arr: &mut [T], order: Order) {
+    heap_sort_iterative_by(arr, |a, b| order.apply(a.cmp(b)));
+}
+
+/// 使用自定义比较函数的迭代版堆排序
+pub fn heap_sort_iterative_by<T, F>(arr: &mut [T], mut compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let n = arr.len();
+    if n <= 1 {
+        return;
+    }
+
+    for i in (0..n / 2).rev() {
+        heapify_iterative_by(arr, n, i, &mut compare);
+    }
+
+    for i in (1..n).rev() {
+        arr.swap(0, i);
+        heapify_iterative_by(arr, i, 0, &mut compare);
+    }
+}
+
+/// 按键排序的迭代版堆排序，比较 `key(a)` 与 `key(b)`
+pub fn heap_sort_iterative_by_key<T, K, F>(arr: &mut [T], mut key: F)
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    heap_sort_iterative_by(arr, |a, b| key(a).cmp(&key(b)));
+}
+
+/// d 叉堆的分叉数
+///
+/// 分叉越多，堆越矮，下沉时访问的层数越少、内存访问越集中，但每层需要更多比较。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HeapArity {
+    /// 二叉堆
+    Binary,
+    /// 三叉堆
+    Ternary,
+    /// 四叉堆
+    Quaternary,
+    /// 八叉堆
+    Octonary,
+}
+
+impl HeapArity {
+    /// 每个节点的子节点个数
+    pub fn children(self) -> usize {
+        match self {
+            HeapArity::Binary => 2,
+            HeapArity::Ternary => 3,
+            HeapArity::Quaternary => 4,
+            HeapArity::Octonary => 8,
+        }
+    }
+}
+
+/// 自底向上堆排序（Floyd），每层约一次比较，普通堆排序每层两次
+pub fn heap_sort_bottom_up<T: Ord>(arr: &mut [T]) {
+    heap_sort_bottom_up_by(arr, T::cmp);
+}
+
+/// 按指定方向排序的自底向上堆排序
+pub fn heap_sort_bottom_up_with_order<T: Ord>(arr: &mut [T], order: Order) {
+    heap_sort_bottom_up_by(arr, |a, b| order.apply(a.cmp(b)));
+}
+
+/// 使用自定义比较函数的自底向上堆排序
+pub fn heap_sort_bottom_up_by<T, F>(arr: &mut [T], compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    heap_sort_d_ary_by(arr, HeapArity::Binary, compare);
+}
+
+/// 按键排序的自底向上堆排序，比较 `key(a)` 与 `key(b)`
+pub fn heap_sort_bottom_up_by_key<T, K, F>(arr: &mut [T], mut key: F)
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    heap_sort_bottom_up_by(arr, |a, b| key(a).cmp(&key(b)));
+}
+
+/// 使用 d 叉堆的自底向上堆排序
+pub fn heap_sort_d_ary<T: Ord>(arr: &mut [T], arity: HeapArity) {
+    heap_sort_d_ary_by(arr, arity, T::cmp);
+}
+
+/// 使用 d 叉堆和自定义比较函数的自底向上堆排序
+///
+/// 建堆用普通的下沉操作（与 [`heapify_iterative_by`] 相同），取出堆顶后用自底向上的下沉。
+pub fn heap_sort_d_ary_by<T, F>(arr: &mut [T], arity: HeapArity, mut compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    match arity {
+        HeapArity::Binary => d_ary_heap_sort::<2, T, F>(arr, &mut compare),
+        HeapArity::Ternary => d_ary_heap_sort::<3, T, F>(arr, &mut compare),
+        HeapArity::Quaternary => d_ary_heap_sort::<4, T, F>(arr, &mut compare),
+        HeapArity::Octonary => d_ary_heap_sort::<8, T, F>(arr, &mut compare),
+    }
+}
+
+fn d_ary_heap_sort<const D: usize, T, F>(arr: &mut [T], compare: &mut F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let n = arr.len();
+    if n <= 1 {
+        return;
+    }
+
+    // 最后一个非叶子节点是最后一个元素的父节点 (n - 2) / D
+    for i in (0..=(n - 2) / D).rev() {
+        sift_down::<D, T, F>(arr, n, i, compare);
+    }
+
+    for i in (1..n).rev() {
+        arr.swap(0, i);
+        sift_down_bottom_up::<D, T, F>(arr, i, 0, compare);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{assert_each_once, random_vec, tracked_vec};
+
+    const ARITIES: [HeapArity; 4] = [
+        HeapArity::Binary,
+        HeapArity::Ternary,
+        HeapArity::Quaternary,
+        HeapArity::Octonary,
+    ];
+
+    /// 排序并返回比较次数
+    fn count_comparisons(
+        arr: &mut [u64],
+        sort: impl FnOnce(&mut [u64], &mut dyn FnMut(&u64, &u64) -> Ordering),
+    ) -> usize {
+        let mut calls = 0;
+        sort(arr, &mut |a, b| {
+            calls += 1;
+            a.cmp(b)
+        });
+        calls
+    }
+
+    #[test]
+    fn test_heap_sort() {
+        let mut arr = vec![12, 11, 13, 5, 6, 7];
+        heap_sort(&mut arr);
+        assert_eq!(arr, vec![5, 6, 7, 11, 12, 13]);
+    }
+
+    #[test]
+    fn test_heap_sort_iterative() {
+        let mut arr = vec![12, 11, 13, 5, 6, 7];
+        heap_sort_iterative(&mut arr);
+        assert_eq!(arr, vec![5, 6, 7, 11, 12, 13]);
+    }
+
+    #[test]
+    fn test_empty() {
+        let mut arr: Vec<i32> = vec![];
+        heap_sort(&mut arr);
+        assert_eq!(arr, vec![]);
+    }
+
+    #[test]
+    fn test_single() {
+        let mut arr = vec![42];
+        heap_sort(&mut arr);
+        assert_eq!(arr, vec![42]);
+    }
+
+    #[test]
+    fn test_duplicates() {
+        let mut arr = vec![3, 1, 4, 1, 5, 9, 2, 6, 5];
+        heap_sort(&mut arr);
+        assert_eq!(arr, vec![1, 1, 2, 3, 4, 5, 5, 6, 9]);
+    }
+
+    #[test]
+    fn test_heap_sort_by() {
+        let expected = vec![13, 12, 11, 7, 6, 5];
+
+        let mut arr = vec![12, 11, 13, 5, 6, 7];
+        heap_sort_by(&mut arr, |a, b| b.cmp(a));
+        assert_eq!(arr, expected);
+
+        let mut arr = vec![12, 11, 13, 5, 6, 7];
+        heap_sort_iterative_by(&mut arr, |a, b| b.cmp(a));
+        assert_eq!(arr, expected);
+    }
+
+    #[test]
+    fn test_heap_sort_by_key() {
+        let mut arr = vec!["ccc", "a", "bb", "dddd"];
+        heap_sort_by_key(&mut arr, |s| s.len());
+        assert_eq!(arr, vec!["a", "bb", "ccc", "dddd"]);
+    }
+
+    #[test]
+    fn test_heap_sort_bottom_up() {
+        let mut arr = vec![12, 11, 13, 5, 6, 7];
+        heap_sort_bottom_up(&mut arr);
+        assert_eq!(arr, vec![5, 6, 7, 11, 12, 13]);
+
+        let mut arr = vec![12, 11, 13, 5, 6, 7];
+        heap_sort_bottom_up_with_order(&mut arr, Order::Descending);
+        assert_eq!(arr, vec![13, 12, 11, 7, 6, 5]);
+    }
+
+    #[test]
+    fn test_heap_sort_d_ary() {
+        for arity in ARITIES {
+            for len in [0, 1, 2, 3, 8, 9, 10, 1000] {
+                for bound in [3, 1_000_000] {
+                    let input = random_vec(len, bound, len as u64 + bound);
+                    let mut expected = input.clone();
+                    expected.sort();
+
+                    let mut arr = input;
+                    heap_sort_d_ary(&mut arr, arity);
+                    assert_eq!(arr, expected, "{:?}, len = {}", arity, len);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_bottom_up_fewer_comparisons() {
+        let input = random_vec(10_000, 1_000_000, 61);
+
+        let mut arr = input.clone();
+        let classic = count_comparisons(&mut arr, |a, c| heap_sort_iterative_by(a, c));
+        let mut arr = input;
+        let bottom_up = count_comparisons(&mut arr, |a, c| heap_sort_bottom_up_by(a, c));
+        assert!(arr.windows(2).all(|w| w[0] <= w[1]));
+
+        // 取出堆顶占了大部分比较，自底向上每层只需约一次比较
+        assert!(
+            bottom_up * 10 < classic * 7,
+            "自底向上 {} 次，普通 {} 次",
+            bottom_up,
+            classic
+        );
+    }
+
+    #[test]
+    fn test_d_ary_moves_without_clone() {
+        for arity in ARITIES {
+            let (mut arr, drops) = tracked_vec(&random_vec(300, 20, 62));
+            heap_sort_d_ary(&mut arr, arity);
+            assert!(arr.windows(2).all(|w| w[0].key <= w[1].key));
+            assert_each_once(arr, &drops);
+        }
+    }
+}
@@ -0,0 +1,397 @@
+//! 插入排序 (Insertion Sort)
+//!
+//! # 算法原理
+//! 插入排序的工作方式就像我们整理扑克牌一样：
+//! 1. 从第一个元素开始，该元素可以认为已经被排序
+//! 2. 取出下一个元素，在已经排序的元素序列中从后向前扫描
+//! 3. 如果已排序的元素大于新元素，将该元素移到下一位置
+//! 4. 重复步骤3，直到找到已排序的元素小于或等于新元素的位置
+//! 5. 将新元素插入到该位置后
+//! 6. 重复步骤2~5，直到所有元素都被排序
+//!
+//! # 时间复杂度
+//! - 最好情况: O(n) - 当数组已经有序时
+//! - 最坏情况: O(n²) - 当数组逆序时
+//! - 平均情况: O(n²)
+//!
+//! # 空间复杂度
+//! O(1) - 只需要常数级别的额外空间
+//!
+//! # 稳定性
+//! 稳定排序 - 相等元素的相对顺序不会改变
+//!
+//! # 适用场景
+//! - 小规模数据
+//! - 数据基本有序的情况
+//! - 作为其他高级排序算法的子过程（如快速排序的小数组优化）
+
+use crate::Order;
+use std::cmp::Ordering;
+use std::mem::ManuallyDrop;
+use std::ptr;
+
+/// 插入排序函数（移动版本）
+/// 使用元素移动而非交换，效率更高
+///
+/// 元素按位移动，不需要 `Clone`，`String`、`Box<dyn Trait>` 等类型也可以直接排序。
+///
+/// # 参数
+/// - `arr`: 待排序的可变数组切片
+pub fn insertion_sort<T: Ord>(arr: &mut [T]) {
+    insertion_sort_by(arr, T::cmp);
+}
+
+/// 按指定方向排序的插入排序，降序时依然稳定
+pub fn insertion_sort_with_order<T: Ord>(arr: &mut [T], order: Order) {
+    insertion_sort_by(arr, |a, b| order.apply(a.cmp(b)));
+}
+
+/// 使用自定义比较函数的插入排序（移动版本）
+///
+/// 只有 `compare` 返回 `Ordering::Greater` 时才移动元素，因此保持稳定。
+pub fn insertion_sort_by<T, F>(arr: &mut [T], mut compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let n = arr.len();
+
+    // 如果数组长度小于等于1，无需排序
+    if n <= 1 {
+        return;
+    }
+
+    // 从第二个元素开始，因为第一个元素自己就是有序的
+    for i in 1..n {
+        insert_tail_by(arr, i, 1, &mut compare);
+    }
+}
+
+/// 按键排序的插入排序，比较 `key(a)` 与 `key(b)`
+pub fn insertion_sort_by_key<T, K, F>(arr: &mut [T], mut key: F)
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    insertion_sort_by(arr, |a, b| key(a).cmp(&key(b)));
+}
+
+/// 把 `arr[i]` 插入到 `arr[i - gap], arr[i - 2 * gap], ...` 组成的有序序列中
+///
+/// 先把 `arr[i]` 取出，留下一个“空位”，比它大的元素依次移进空位，
+/// 最后把取出的元素写入空位。整个过程只移动元素，不复制也不释放任何元素。
+/// `gap` 为 1 时就是普通的插入排序，希尔排序使用更大的 `gap`。
+pub(crate) fn insert_tail_by<T, F>(arr: &mut [T], i: usize, gap: usize, compare: &mut F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    debug_assert!(gap > 0 && i < arr.len());
+
+    // 已经在正确位置，不需要取出
+    if i < gap || !compare(&arr[i - gap], &arr[i]).is_gt() {
+        return;
+    }
+
+    let base = arr.as_mut_ptr();
+    // SAFETY: 所有下标都小于 `arr.len()`。取出的元素由 `hole` 持有，
+    // 每次移动后 `hole.dest` 都指向唯一的空位；即使 `compare` panic，
+    // `hole` 析构时也会把元素写回空位，数组中每个元素始终恰好出现一次。
+    unsafe {
+        let mut hole = Hole {
+            value: ManuallyDrop::new(ptr::read(base.add(i))),
+            dest: base.add(i),
+        };
+        ptr::copy_nonoverlapping(base.add(i - gap), base.add(i), 1);
+        hole.dest = base.add(i - gap);
+
+        let mut j = i - gap;
+        while j >= gap && compare(&*base.add(j - gap), &hole.value).is_gt() {
+            ptr::copy_nonoverlapping(base.add(j - gap), base.add(j), 1);
+            j -= gap;
+            hole.dest = base.add(j);
+        }
+        // `hole` 在这里析构，把取出的元素写入最后的空位
+    }
+}
+
+/// 插入过程中被取出的元素，析构时写回 `dest` 指向的空位
+struct Hole<T> {
+    value: ManuallyDrop<T>,
+    dest: *mut T,
+}
+
+impl<T> Drop for Hole<T> {
+    fn drop(&mut self) {
+        // SAFETY: `dest` 指向一个已经被移走的位置，写入不会覆盖有效的元素
+        unsafe {
+            ptr::copy_nonoverlapping(&*self.value, self.dest, 1);
+        }
+    }
+}
+
+/// 插入排序函数（交换版本）
+/// 使用相邻元素交换，代码更简洁但效率稍低
+pub fn insertion_sort_swap<T: Ord>(arr: &mut [T]) {
+    insertion_sort_swap_by(arr, T::cmp);
+}
+
+/// 按指定方向排序的插入排序（交换版本），降序时依然稳定
+pub fn insertion_sort_swap_with_order<T: Ord>(arr: &mut [T], order: Order) {
+    insertion_sort_swap_by(arr, |a, b| order.apply(a.cmp(b)));
+}
+
+/// 使用自定义比较函数的插入排序（交换版本）
+pub fn insertion_sort_swap_by<T, F>(arr: &mut [T], mut compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let n = arr.len();
+
+    if n <= 1 {
+        return;
+    }
+
+    for i in 1..n {
+        // 从当前位置向前，通过相邻交换将元素移动到正确位置
+        let mut j = i;
+        while j > 0 && compare(&arr[j - 1], &arr[j]).is_gt() {
+            arr.swap(j - 1, j);
+            j -= 1;
+        }
+    }
+}
+
+/// 按键排序的插入排序（交换版本），比较 `key(a)` 与 `key(b)`
+pub fn insertion_sort_swap_by_key<T, K, F>(arr: &mut [T], mut key: F)
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    insertion_sort_swap_by(arr, |a, b| key(a).cmp(&key(b)));
+}
+
+/// 二分插入排序
+/// 使用二分查找来确定插入位置，减少比较次数
+/// 但移动次数仍然是 O(n²)
+pub fn binary_insertion_sort<T: Ord>(arr: &mut [T]) {
+    binary_insertion_sort_by(arr, T::cmp);
+}
+
+/// 按指定方向排序的二分插入排序，降序时依然稳定
+pub fn binary_insertion_sort_with_order<T: Ord>(arr: &mut [T], order: Order) {
+    binary_insertion_sort_by(arr, |a, b| order.apply(a.cmp(b)));
+}
+
+/// 使用自定义比较函数的二分插入排序
+pub fn binary_insertion_sort_by<T, F>(arr: &mut [T], mut compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    if arr.len() <= 1 {
+        return;
+    }
+
+    binary_insertion_sort_from_by(arr, 1, &mut compare);
+}
+
+/// 二分插入排序的主体：`arr[..sorted_len]` 已经有序，把之后的元素依次插入
+///
+/// Timsort 用它把较短的自然有序段扩展到最小长度。
+pub(crate) fn binary_insertion_sort_from_by<T, F>(arr: &mut [T], sorted_len: usize, compare: &mut F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    for i in sorted_len.max(1)..arr.len() {
+        // 使用二分查找在已排序部分 [0, i) 中找到插入位置
+        // 找到第一个大于 arr[i] 的元素位置
+        let (sorted, rest) = arr.split_at(i);
+        let insert_pos = binary_search_insert_position_by(sorted, &rest[0], compare);
+
+        // 将 [insert_pos, i) 范围内的元素向后移动一位
+        // 使用 rotate_right 可以高效地完成这个操作，只移动元素不复制
+        arr[insert_pos..=i].rotate_right(1);
+    }
+}
+
+/// 按键排序的二分插入排序，比较 `key(a)` 与 `key(b)`
+pub fn binary_insertion_sort_by_key<T, K, F>(arr: &mut [T], mut key: F)
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    binary_insertion_sort_by(arr, |a, b| key(a).cmp(&key(b)));
+}
+
+/// 二分查找插入位置
+/// 返回第一个大于 target 的元素的索引
+pub(crate) fn binary_search_insert_position_by<T, F>(
+    arr: &[T],
+    target: &T,
+    compare: &mut F,
+) -> usize
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let mut left = 0;
+    let mut right = arr.len();
+
+    while left < right {
+        let mid = left + (right - left) / 2;
+
+        // 使用 <= 保证稳定性
+        // 当遇到相等元素时，继续在右半部分查找
+        if compare(&arr[mid], target).is_le() {
+            left = mid + 1;
+        } else {
+            right = mid;
+        }
+    }
+
+    left
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{assert_each_once, random_vec, tracked_vec, Tracked};
+    use std::panic::{self, AssertUnwindSafe};
+
+    #[test]
+    fn test_insertion_sort() {
+        let mut arr = vec![12, 11, 13, 5, 6];
+        insertion_sort(&mut arr);
+        assert_eq!(arr, vec![5, 6, 11, 12, 13]);
+    }
+
+    #[test]
+    fn test_insertion_sort_swap() {
+        let mut arr = vec![12, 11, 13, 5, 6];
+        insertion_sort_swap(&mut arr);
+        assert_eq!(arr, vec![5, 6, 11, 12, 13]);
+    }
+
+    #[test]
+    fn test_binary_insertion_sort() {
+        let mut arr = vec![12, 11, 13, 5, 6];
+        binary_insertion_sort(&mut arr);
+        assert_eq!(arr, vec![5, 6, 11, 12, 13]);
+    }
+
+    #[test]
+    fn test_empty_array() {
+        let mut arr: Vec<i32> = vec![];
+        insertion_sort(&mut arr);
+        assert_eq!(arr, vec![]);
+    }
+
+    #[test]
+    fn test_single_element() {
+        let mut arr = vec![42];
+        insertion_sort(&mut arr);
+        assert_eq!(arr, vec![42]);
+    }
+
+    #[test]
+    fn test_sorted_array() {
+        let mut arr = vec![1, 2, 3, 4, 5];
+        insertion_sort(&mut arr);
+        assert_eq!(arr, vec![1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn test_reversed_array() {
+        let mut arr = vec![5, 4, 3, 2, 1];
+        insertion_sort(&mut arr);
+        assert_eq!(arr, vec![1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn test_with_duplicates() {
+        let mut arr = vec![3, 1, 4, 1, 5, 9, 2, 6, 5, 3, 5];
+        insertion_sort(&mut arr);
+        assert_eq!(arr, vec![1, 1, 2, 3, 3, 4, 5, 5, 5, 6, 9]);
+    }
+
+    #[test]
+    fn test_stability() {
+        // 测试稳定性：相等元素应保持原有顺序
+        #[derive(Clone, Debug, PartialEq, Eq)]
+        struct Item {
+            key: i32,
+            index: usize,
+        }
+
+        impl PartialOrd for Item {
+            fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl Ord for Item {
+            fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+                self.key.cmp(&other.key)
+            }
+        }
+
+        let mut arr = vec![
+            Item { key: 3, index: 0 },
+            Item { key: 1, index: 1 },
+            Item { key: 3, index: 2 },
+            Item { key: 1, index: 3 },
+        ];
+
+        insertion_sort(&mut arr);
+
+        // 验证相等元素保持原有的相对顺序
+        assert!(arr[0].key == 1 && arr[0].index == 1);
+        assert!(arr[1].key == 1 && arr[1].index == 3);
+        assert!(arr[2].key == 3 && arr[2].index == 0);
+        assert!(arr[3].key == 3 && arr[3].index == 2);
+    }
+
+    #[test]
+    fn test_insertion_sort_by() {
+        let mut arr = vec![12, 11, 13, 5, 6];
+        insertion_sort_by(&mut arr, |a, b| b.cmp(a));
+        assert_eq!(arr, vec![13, 12, 11, 6, 5]);
+
+        let mut arr = vec![12, 11, 13, 5, 6];
+        insertion_sort_swap_by(&mut arr, |a, b| b.cmp(a));
+        assert_eq!(arr, vec![13, 12, 11, 6, 5]);
+    }
+
+    #[test]
+    fn test_binary_insertion_sort_by_stability() {
+        // 只按第一个分量比较，第二个分量记录原始顺序
+        let mut arr = vec![(3, 'a'), (1, 'b'), (3, 'c'), (2, 'd'), (1, 'e')];
+        binary_insertion_sort_by(&mut arr, |a, b| a.0.cmp(&b.0));
+        assert_eq!(arr, vec![(1, 'b'), (1, 'e'), (2, 'd'), (3, 'a'), (3, 'c')]);
+    }
+
+    #[test]
+    fn test_moves_without_clone() {
+        let keys = random_vec(200, 20, 11);
+        for sort in [insertion_sort::<Tracked>, binary_insertion_sort::<Tracked>] {
+            let (mut arr, drops) = tracked_vec(&keys);
+            sort(&mut arr);
+            assert!(arr
+                .windows(2)
+                .all(|w| (w[0].key, w[0].id) < (w[1].key, w[1].id)));
+            assert_each_once(arr, &drops);
+        }
+    }
+
+    #[test]
+    fn test_panic_in_compare_keeps_every_element() {
+        let (mut arr, drops) = tracked_vec(&random_vec(50, 100, 12));
+        let mut calls = 0;
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            insertion_sort_by(&mut arr, |a, b| {
+                calls += 1;
+                assert!(calls < 300, "比较函数 panic");
+                a.cmp(b)
+            })
+        }));
+        assert!(result.is_err());
+        assert_each_once(arr, &drops);
+    }
+}
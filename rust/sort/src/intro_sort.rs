@@ -0,0 +1,218 @@
+//! 内省排序 (Introsort)
+//!
+//! # 算法原理
+//! 内省排序以快速排序为主体，并监视递归深度，避免快速排序的最坏情况：
+//! 1. 用三数取中选择基准，再用 Hoare 分区（或通过 `intro_sort_with_scheme` 指定的其他分区方案）
+//!    把数组分成两部分
+//! 2. 只对较小的一侧递归，较大的一侧在循环中继续处理，递归深度不超过 O(log n)
+//! 3. 递归深度超过 2·log2(n) 时，说明分区持续失衡，改用堆排序处理当前区间
+//! 4. 区间足够小时用插入排序收尾
+//!
+//! 有序、逆序输入在普通快速排序（取最后一个元素作为基准）中会退化为 O(n²)，
+//! 递归深度为 n，十万个元素左右就会栈溢出；内省排序在这些输入上依然是 O(n log n)。
+//!
+//! # 时间复杂度: O(n log n) - 所有情况
+//! # 空间复杂度: O(log n) - 递归栈空间
+//! # 稳定性: 不稳定
+
+use crate::heap_sort::heap_sort_iterative_by;
+use crate::insertion_sort::insertion_sort_by;
+use crate::quick_sort::PartitionScheme;
+use crate::Order;
+use std::cmp::Ordering;
+
+/// 区间长度不超过该值时用插入排序收尾
+const INSERTION_THRESHOLD: usize = 16;
+
+/// 内省排序主函数
+pub fn intro_sort<T: Ord>(arr: &mut [T]) {
+    intro_sort_by(arr, T::cmp);
+}
+
+/// 按指定方向排序的内省排序
+pub fn intro_sort_with_order<T: Ord>(arr: &mut [T], order: Order) {
+    intro_sort_by(arr, |a, b| order.apply(a.cmp(b)));
+}
+
+/// 使用自定义比较函数的内省排序
+pub fn intro_sort_by<T, F>(arr: &mut [T], compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    intro_sort_with_scheme_by(arr, PartitionScheme::Hoare, compare);
+}
+
+/// 按键排序的内省排序，比较 `key(a)` 与 `key(b)`
+pub fn intro_sort_by_key<T, K, F>(arr: &mut [T], mut key: F)
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    intro_sort_by(arr, |a, b| key(a).cmp(&key(b)));
+}
+
+/// 使用指定分区方案的内省排序，默认使用 Hoare 分区
+pub fn intro_sort_with_scheme<T: Ord>(arr: &mut [T], scheme: PartitionScheme) {
+    intro_sort_with_scheme_by(arr, scheme, T::cmp);
+}
+
+/// 使用指定分区方案和自定义比较函数的内省排序
+pub fn intro_sort_with_scheme_by<T, F>(arr: &mut [T], scheme: PartitionScheme, mut compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let depth_limit = 2 * log2(arr.len());
+    intro_sort_loop(arr, depth_limit, scheme, &mut compare);
+}
+
+/// `floor(log2(n))`，`n` 为 0 时返回 0
+fn log2(n: usize) -> usize {
+    n.checked_ilog2().unwrap_or(0) as usize
+}
+
+fn intro_sort_loop<T, F>(
+    mut arr: &mut [T],
+    mut depth_limit: usize,
+    scheme: PartitionScheme,
+    compare: &mut F,
+) where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    loop {
+        if arr.len() <= INSERTION_THRESHOLD {
+            insertion_sort_by(arr, &mut *compare);
+            return;
+        }
+
+        // 分区持续失衡，改用堆排序保证 O(n log n)
+        if depth_limit == 0 {
+            heap_sort_iterative_by(arr, &mut *compare);
+            return;
+        }
+        depth_limit -= 1;
+
+        // 三数取中：中位数放到中间，各个分区方案都以中间元素为基准
+        median_of_three(arr, compare);
+        let pivot_index = scheme.partition_by(arr, &mut *compare);
+
+        let (left, right) = arr.split_at_mut(pivot_index);
+        let right = &mut right[1..];
+
+        // 只递归较小的一侧，较大的一侧留给下一轮循环
+        if left.len() < right.len() {
+            intro_sort_loop(left, depth_limit, scheme, compare);
+            arr = right;
+        } else {
+            intro_sort_loop(right, depth_limit, scheme, compare);
+            arr = left;
+        }
+    }
+}
+
+/// 把首、中、尾三个元素排好序，使中位数位于中间
+pub(crate) fn median_of_three<T, F>(arr: &mut [T], compare: &mut F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let (a, b, c) = (0, arr.len() / 2, arr.len() - 1);
+    if compare(&arr[b], &arr[a]).is_lt() {
+        arr.swap(a, b);
+    }
+    if compare(&arr[c], &arr[b]).is_lt() {
+        arr.swap(b, c);
+        if compare(&arr[b], &arr[a]).is_lt() {
+            arr.swap(a, b);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::random_vec;
+
+    /// 排序并返回比较次数
+    fn count_comparisons(arr: &mut [u64]) -> usize {
+        let mut calls = 0;
+        intro_sort_by(arr, |a, b| {
+            calls += 1;
+            a.cmp(b)
+        });
+        calls
+    }
+
+    #[test]
+    fn test_intro_sort() {
+        let mut arr = vec![10, 7, 8, 9, 1, 5];
+        intro_sort(&mut arr);
+        assert_eq!(arr, vec![1, 5, 7, 8, 9, 10]);
+
+        let mut arr: Vec<i32> = vec![];
+        intro_sort(&mut arr);
+        assert_eq!(arr, vec![]);
+
+        let input = random_vec(1000, 100, 71);
+        let mut expected = input.clone();
+        expected.sort();
+        let mut arr = input.clone();
+        intro_sort(&mut arr);
+        assert_eq!(arr, expected);
+
+        let mut arr = input;
+        intro_sort_with_order(&mut arr, Order::Descending);
+        expected.reverse();
+        assert_eq!(arr, expected);
+    }
+
+    #[test]
+    fn test_sorted_and_reversed_inputs() {
+        // 普通快速排序在这些输入上递归深度为 n，会栈溢出
+        let n = 200_000;
+        let sorted: Vec<u64> = (0..n).collect();
+        let bound = 4 * n as usize * log2(n as usize);
+
+        let mut arr = sorted.clone();
+        assert!(count_comparisons(&mut arr) < bound);
+        assert_eq!(arr, sorted);
+
+        let mut arr: Vec<u64> = (0..n).rev().collect();
+        assert!(count_comparisons(&mut arr) < bound);
+        assert_eq!(arr, sorted);
+
+        let mut arr = vec![7u64; n as usize];
+        assert!(count_comparisons(&mut arr) < bound);
+    }
+
+    #[test]
+    fn test_heap_sort_fallback() {
+        // 深度上限为 0 时直接用堆排序处理整个区间
+        let input = random_vec(500, 1000, 72);
+        let mut expected = input.clone();
+        expected.sort();
+        let mut arr = input;
+        intro_sort_loop(&mut arr, 0, PartitionScheme::Hoare, &mut u64::cmp);
+        assert_eq!(arr, expected);
+    }
+
+    #[test]
+    fn test_intro_sort_with_scheme() {
+        let n = 200_000;
+        for scheme in [
+            PartitionScheme::Lomuto,
+            PartitionScheme::Hoare,
+            PartitionScheme::Block,
+        ] {
+            // 有序和逆序输入上，三数取中使各个方案都不会退化
+            let mut arr: Vec<u64> = (0..n).rev().collect();
+            intro_sort_with_scheme(&mut arr, scheme);
+            assert!(arr.windows(2).all(|w| w[0] <= w[1]), "{:?}", scheme);
+
+            let input = random_vec(5000, 10, 73);
+            let mut expected = input.clone();
+            expected.sort();
+            let mut arr = input;
+            intro_sort_with_scheme(&mut arr, scheme);
+            assert_eq!(arr, expected, "{:?}", scheme);
+        }
+    }
+}
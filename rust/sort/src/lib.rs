@@ -0,0 +1,143 @@
+//! 排序算法集合
+//!
+//! 每个模块对应一种排序算法，模块内的函数都可以直接调用：
+//!
+//! ```
+//! use sort::quick_sort::quick_sort;
+//!
+//! let mut arr = vec![10, 7, 8, 9, 1, 5];
+//! quick_sort(&mut arr);
+//! assert_eq!(arr, vec![1, 5, 7, 8, 9, 10]);
+//! ```
+//!
+//! 所有算法也都实现了统一的 [`SortAlgorithm`] 接口，并登记在 [`registry`] 中，
+//! 调用方可以在运行时按名称选择算法：
+//!
+//! ```
+//! use sort::{registry, SortAlgorithm};
+//!
+//! let algorithms = registry::comparison_sorts::<i32>();
+//! let heap = algorithms.get("heap_sort").unwrap();
+//!
+//! let mut arr = vec![12, 11, 13, 5, 6, 7];
+//! heap.sort(&mut arr);
+//! assert_eq!(arr, vec![5, 6, 7, 11, 12, 13]);
+//! ```
+
+pub mod block_merge_sort;
+pub mod bubble_sort;
+pub mod bucket_sort;
+pub mod cached_key;
+pub mod counting_sort;
+pub mod error;
+pub mod heap_sort;
+pub mod insertion_sort;
+pub mod intro_sort;
+pub mod merge_sort;
+pub mod pdq_sort;
+pub mod quick_sort;
+pub mod radix_key;
+pub mod radix_sort;
+pub mod registry;
+pub mod selection_sort;
+pub mod shell_sort;
+pub mod smooth_sort;
+pub mod tim_sort;
+
+#[cfg(test)]
+mod test_util;
+
+pub use counting_sort::CountingKey;
+pub use error::SortError;
+pub use radix_key::RadixKey;
+pub use registry::{Algorithm, Registry};
+
+use std::cmp::Ordering;
+
+/// 排序方向
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Order {
+    /// 升序（默认）
+    #[default]
+    Ascending,
+    /// 降序
+    Descending,
+}
+
+impl Order {
+    /// 把升序下的比较结果转换为当前方向下的比较结果
+    ///
+    /// 降序时只交换 `Less` 和 `Greater`，`Equal` 保持不变，
+    /// 所以稳定的排序算法在降序下依然稳定。
+    pub fn apply(self, ordering: Ordering) -> Ordering {
+        match self {
+            Order::Ascending => ordering,
+            Order::Descending => ordering.reverse(),
+        }
+    }
+}
+
+/// 并行排序的参数
+///
+/// ```
+/// use sort::Parallelism;
+///
+/// let parallelism = Parallelism::new(4).with_sequential_cutoff(1 << 16);
+/// assert_eq!(parallelism.threads, 4);
+/// assert_eq!(parallelism.sequential_cutoff, 1 << 16);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Parallelism {
+    /// 最多同时运行的线程数（包括调用方线程），0 按 1 处理
+    pub threads: usize,
+    /// 不超过该长度的数组（或子数组）不再拆分，在当前线程中顺序排序
+    pub sequential_cutoff: usize,
+}
+
+impl Parallelism {
+    /// 默认的顺序排序阈值，更短的数组创建线程的开销超过并行带来的收益
+    pub const DEFAULT_SEQUENTIAL_CUTOFF: usize = 1 << 13;
+
+    /// 使用 `threads` 个线程和默认的顺序排序阈值
+    pub const fn new(threads: usize) -> Self {
+        Parallelism {
+            threads,
+            sequential_cutoff: Self::DEFAULT_SEQUENTIAL_CUTOFF,
+        }
+    }
+
+    /// 修改顺序排序阈值
+    pub const fn with_sequential_cutoff(self, sequential_cutoff: usize) -> Self {
+        Parallelism {
+            sequential_cutoff,
+            ..self
+        }
+    }
+}
+
+impl Default for Parallelism {
+    /// 线程数取 [`std::thread::available_parallelism`]，无法获取时为 1
+    fn default() -> Self {
+        Parallelism::new(std::thread::available_parallelism().map_or(1, |n| n.get()))
+    }
+}
+
+/// 排序算法的统一接口
+///
+/// `T` 是算法能够处理的元素类型：比较排序对任意 `T: Ord` 实现，
+/// 计数、基数、桶排序等非比较排序只对特定的元素类型实现。
+pub trait SortAlgorithm<T> {
+    /// 算法名称，在注册表中唯一
+    fn name(&self) -> &'static str;
+
+    /// 是否为稳定排序（相等元素保持原有的相对顺序）
+    fn is_stable(&self) -> bool;
+
+    /// 原地将 `arr` 按 `order` 指定的方向排序
+    fn sort_with_order(&self, arr: &mut [T], order: Order);
+
+    /// 原地将 `arr` 按升序排序
+    fn sort(&self, arr: &mut [T]) {
+        self.sort_with_order(arr, Order::Ascending);
+    }
+}
@@ -0,0 +1,1189 @@
+//! 归并排序 (Merge Sort)
+//!
+//! # 算法原理
+//! 归并排序是分治法的典型应用：
+//! 1. 将数组分成两半
+//! 2. 递归排序两半
+//! 3. 合并两个有序数组
+//!
+//! # 时间复杂度: O(n log n) - 所有情况
+//! # 空间复杂度: O(n) - 需要额外空间存储合并结果
+//! # 稳定性: 稳定排序
+//!
+//! 每次排序只分配一次缓冲区。需要在循环中反复排序时，用 [`merge_sort_with_buffer`]
+//! 传入同一个 `Vec`，之后的排序完全不分配内存。
+//!
+//! 不能分配缓冲区时用 [`merge_sort_in_place`]：合并改用 [`merge_in_place`]（SymMerge），
+//! 只靠二分查找和旋转，不需要额外空间，代价是时间复杂度变为 O(n log² n)。
+//! [`merge_sort`] 分配缓冲区失败时也会自动退回这种方式。
+//!
+//! 多核机器上可以用 [`merge_sort_parallel`]：各线程先分别排序一段，再用 merge path
+//! 把每次合并的输出均分给多个线程，结果与 [`merge_sort`] 完全相同。
+
+use crate::cached_key::sort_by_cached_key;
+use crate::insertion_sort::insertion_sort_by;
+use crate::{Order, Parallelism};
+use std::cmp::Ordering;
+use std::mem::{self, MaybeUninit};
+use std::ptr;
+use std::slice;
+use std::thread;
+
+/// 归并排序主函数
+pub fn merge_sort<T: Ord>(arr: &mut [T]) {
+    merge_sort_by(arr, T::cmp);
+}
+
+/// 按指定方向排序的归并排序，降序时依然稳定
+pub fn merge_sort_with_order<T: Ord>(arr: &mut [T], order: Order) {
+    merge_sort_by(arr, |a, b| order.apply(a.cmp(b)));
+}
+
+/// 使用自定义比较函数的归并排序
+///
+/// 相等（`Ordering::Equal`）的元素保持原有顺序。
+pub fn merge_sort_by<T, F>(arr: &mut [T], mut compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    // 每次合并最多把 n / 2 个元素移到缓冲区，整个排序共用这一个缓冲区；
+    // 分配失败时不使用缓冲区，改为原地合并
+    let mut buf = Vec::new();
+    if buf.try_reserve_exact(arr.len() / 2).is_err() {
+        merge_sort_in_place_by(arr, compare);
+        return;
+    }
+    merge_sort_recursive(arr, buf.spare_capacity_mut(), &mut compare);
+}
+
+/// 按键排序的归并排序，比较 `key(a)` 与 `key(b)`
+///
+/// 每次比较都会重新计算键，键的计算代价较高时改用 [`merge_sort_by_cached_key`]。
+pub fn merge_sort_by_key<T, K, F>(arr: &mut [T], mut key: F)
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    merge_sort_by(arr, |a, b| key(a).cmp(&key(b)));
+}
+
+/// 缓存键的归并排序：每个元素的键只计算一次，结果是稳定的
+pub fn merge_sort_by_cached_key<T, K, F>(arr: &mut [T], key: F)
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    sort_by_cached_key(arr, key, merge_sort);
+}
+
+fn merge_sort_recursive<T, F>(arr: &mut [T], buf: &mut [MaybeUninit<T>], compare: &mut F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let n = arr.len();
+    if n <= 1 {
+        return;
+    }
+
+    // 分割点
+    let mid = n / 2;
+
+    // 递归排序左右两半
+    merge_sort_recursive(&mut arr[..mid], buf, compare);
+    merge_sort_recursive(&mut arr[mid..], buf, compare);
+
+    // 合并两个有序部分
+    merge_adjacent_by(arr, mid, buf, compare);
+}
+
+/// 合并两个有序数组，结果写入 `arr`
+///
+/// `left` 和 `right` 是独立的切片，元素需要复制到 `arr` 中，所以要求 `Clone`；
+/// 归并排序本身使用只移动元素的原地合并。
+pub fn merge<T: Ord + Clone>(arr: &mut [T], left: &[T], right: &[T]) {
+    merge_by(arr, left, right, T::cmp);
+}
+
+/// 使用自定义比较函数合并两个有序数组
+///
+/// 两侧元素相等时优先取左侧元素，保证稳定性。
+pub fn merge_by<T: Clone, F>(arr: &mut [T], left: &[T], right: &[T], mut compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let mut i = 0; // 左数组索引
+    let mut j = 0; // 右数组索引
+    let mut k = 0; // 结果数组索引
+
+    // 比较并合并
+    while i < left.len() && j < right.len() {
+        if compare(&left[i], &right[j]).is_le() {
+            arr[k] = left[i].clone();
+            i += 1;
+        } else {
+            arr[k] = right[j].clone();
+            j += 1;
+        }
+        k += 1;
+    }
+
+    // 复制剩余元素
+    while i < left.len() {
+        arr[k] = left[i].clone();
+        i += 1;
+        k += 1;
+    }
+
+    while j < right.len() {
+        arr[k] = right[j].clone();
+        j += 1;
+        k += 1;
+    }
+}
+
+/// 原地合并相邻的有序区间 `arr[..mid]` 和 `arr[mid..]`
+///
+/// 左半部分先移动到 `buf`（长度至少为 `mid`，调用前后都不含有效元素），
+/// 再依次把 `buf` 与右半部分中较小的元素移回 `arr`。元素只移动不复制，所以不需要 `Clone`。
+/// 两侧元素相等时优先取左侧元素，保证稳定性。
+pub(crate) fn merge_adjacent_by<T, F>(
+    arr: &mut [T],
+    mid: usize,
+    buf: &mut [MaybeUninit<T>],
+    compare: &mut F,
+) where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let len = arr.len();
+    if mid == 0 || mid >= len {
+        return;
+    }
+    assert!(buf.len() >= mid);
+
+    let v = arr.as_mut_ptr();
+    let b = buf.as_mut_ptr() as *mut T;
+    // SAFETY: `buf` 与 `arr` 不重叠且至少能容纳 `mid` 个元素。
+    // 合并过程中始终满足 `dest + (end - start) == right`：`arr` 中 `[dest, right)`
+    // 这一段正好能放下 `buf` 中剩余的元素。`hole` 析构时（包括 `compare` panic 时）
+    // 把它们移回 `arr`，每个元素始终恰好出现一次。
+    unsafe {
+        ptr::copy_nonoverlapping(v, b, mid);
+        let mut hole = MergeHole {
+            start: b,
+            end: b.add(mid),
+            dest: v,
+        };
+        let mut right = v.add(mid);
+        let right_end = v.add(len);
+
+        while hole.start < hole.end && right < right_end {
+            if compare(&*hole.start, &*right).is_le() {
+                ptr::copy_nonoverlapping(hole.start, hole.dest, 1);
+                hole.start = hole.start.add(1);
+            } else {
+                ptr::copy_nonoverlapping(right, hole.dest, 1);
+                right = right.add(1);
+            }
+            hole.dest = hole.dest.add(1);
+        }
+        // 右半部分剩余的元素已经在正确位置，`hole` 析构时移回左半部分剩余的元素
+    }
+}
+
+/// 合并时 `buf` 中尚未移回的元素 `[start, end)`，析构时移动到 `dest`
+///
+/// 使用者负责保证 `dest` 开始的 `end - start` 个位置正好是数组中的空位。
+pub(crate) struct MergeHole<T> {
+    pub(crate) start: *mut T,
+    pub(crate) end: *mut T,
+    pub(crate) dest: *mut T,
+}
+
+impl<T> Drop for MergeHole<T> {
+    fn drop(&mut self) {
+        // 零大小类型的指针加减不改变地址，算不出剩余的个数，移动它们也不需要做任何事
+        let size = mem::size_of::<T>();
+        if size == 0 {
+            return;
+        }
+        // SAFETY: 见结构体上的约定，以及 `merge_adjacent_by` 中的不变式
+        unsafe {
+            let len = (self.end.addr() - self.start.addr()) / size;
+            ptr::copy_nonoverlapping(self.start, self.dest, len);
+        }
+    }
+}
+
+/// 不使用缓冲区，原地合并相邻的有序区间 `arr[..mid]` 和 `arr[mid..]`
+///
+/// 与 [`merge`] 不同，两个区间都在 `arr` 中，元素只交换不复制，所以不需要 `Clone`。
+///
+/// # 示例
+/// ```
+/// use sort::merge_sort::merge_in_place;
+///
+/// let mut arr = vec![1, 4, 6, 9, 2, 3, 7];
+/// merge_in_place(&mut arr, 4);
+/// assert_eq!(arr, vec![1, 2, 3, 4, 6, 7, 9]);
+/// ```
+pub fn merge_in_place<T: Ord>(arr: &mut [T], mid: usize) {
+    merge_in_place_by(arr, mid, T::cmp);
+}
+
+/// 使用自定义比较函数原地合并相邻的有序区间（SymMerge）
+///
+/// 把两段看成 `[a | b]`，二分查找出分割点，使 `a` 的后段与 `b` 的前段交换位置后
+/// 得到两对更小的相邻有序区间，旋转后分别递归合并；某一段只剩一个元素时，
+/// 像二分插入排序一样找到插入位置后旋转一次。
+/// 比较 O(m log(n/m + 1)) 次（m ≤ n 为两段长度），移动 O((m + n) log(m + n)) 次，
+/// 递归深度 O(log(m + n))。两侧元素相等时左侧元素在前，保证稳定性。
+pub fn merge_in_place_by<T, F>(arr: &mut [T], mid: usize, mut compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    assert!(mid <= arr.len());
+    sym_merge(arr, mid, &mut compare);
+}
+
+fn sym_merge<T, F>(arr: &mut [T], mid: usize, compare: &mut F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let n = arr.len();
+    if mid == 0 || mid == n {
+        return;
+    }
+
+    // 左侧只有一个元素：插到右侧第一个不小于它的元素之前
+    if mid == 1 {
+        let pos = 1 + arr[1..].partition_point(|x| compare(x, &arr[0]).is_lt());
+        arr[..pos].rotate_left(1);
+        return;
+    }
+    // 右侧只有一个元素：插到左侧第一个大于它的元素之前
+    if n - mid == 1 {
+        let pos = arr[..mid].partition_point(|x| compare(&arr[mid], x).is_ge());
+        arr[pos..].rotate_right(1);
+        return;
+    }
+
+    // 以整体中点 `half` 为对称轴，找最小的 `start`，使得 `arr[start..mid]` 与
+    // 关于轴对称的 `arr[mid..end]` 交换后两边都有序（`end = half + mid - start`）
+    let half = n / 2;
+    let sum = half + mid;
+    let (mut start, mut r) = if mid > half {
+        (sum - n, half)
+    } else {
+        (0, mid)
+    };
+    while start < r {
+        let c = start + (r - start) / 2;
+        if compare(&arr[sum - 1 - c], &arr[c]).is_ge() {
+            start = c + 1;
+        } else {
+            r = c;
+        }
+    }
+    let end = sum - start;
+
+    arr[start..end].rotate_left(mid - start);
+    sym_merge(&mut arr[..half], start, compare);
+    sym_merge(&mut arr[half..], end - half, compare);
+}
+
+/// 自底向上的归并排序（迭代版本）
+pub fn merge_sort_iterative<T: Ord>(arr: &mut [T]) {
+    merge_sort_iterative_by(arr, T::cmp);
+}
+
+/// 按指定方向排序的自底向上归并排序，降序时依然稳定
+pub fn merge_sort_iterative_with_order<T: Ord>(arr: &mut [T], order: Order) {
+    merge_sort_iterative_by(arr, |a, b| order.apply(a.cmp(b)));
+}
+
+/// 使用自定义比较函数的自底向上归并排序
+pub fn merge_sort_iterative_by<T, F>(arr: &mut [T], mut compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let n = arr.len();
+    if n <= 1 {
+        return;
+    }
+
+    // 左半部分最多 n - 1 个元素，所有合并共用这一个缓冲区
+    let mut buf = Vec::with_capacity(n);
+    let buf = buf.spare_capacity_mut();
+
+    let mut size = 1; // 当前合并的子数组大小
+
+    while size < n {
+        let mut left = 0;
+
+        while left < n - size {
+            let mid = left + size;
+            let right = std::cmp::min(left + 2 * size, n);
+
+            // 合并 [left, mid) 和 [mid, right)
+            merge_adjacent_by(&mut arr[left..right], mid - left, buf, &mut compare);
+
+            left += 2 * size;
+        }
+
+        size *= 2;
+    }
+}
+
+/// 按键排序的自底向上归并排序，比较 `key(a)` 与 `key(b)`
+pub fn merge_sort_iterative_by_key<T, K, F>(arr: &mut [T], mut key: F)
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    merge_sort_iterative_by(arr, |a, b| key(a).cmp(&key(b)));
+}
+
+/// 原地归并排序的有序段不超过该长度时直接用插入排序
+const IN_PLACE_RUN_LEN: usize = 16;
+
+/// 不使用缓冲区的归并排序，用 [`merge_in_place`] 合并
+///
+/// 只需要 O(log n) 的递归栈空间，时间复杂度为 O(n log² n)。
+pub fn merge_sort_in_place<T: Ord>(arr: &mut [T]) {
+    merge_sort_in_place_by(arr, T::cmp);
+}
+
+/// 按指定方向排序的原地归并排序，降序时依然稳定
+pub fn merge_sort_in_place_with_order<T: Ord>(arr: &mut [T], order: Order) {
+    merge_sort_in_place_by(arr, |a, b| order.apply(a.cmp(b)));
+}
+
+/// 使用自定义比较函数的原地归并排序
+pub fn merge_sort_in_place_by<T, F>(arr: &mut [T], mut compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    merge_sort_in_place_recursive(arr, &mut compare);
+}
+
+/// 按键排序的原地归并排序，比较 `key(a)` 与 `key(b)`
+pub fn merge_sort_in_place_by_key<T, K, F>(arr: &mut [T], mut key: F)
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    merge_sort_in_place_by(arr, |a, b| key(a).cmp(&key(b)));
+}
+
+fn merge_sort_in_place_recursive<T, F>(arr: &mut [T], compare: &mut F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let n = arr.len();
+    if n <= IN_PLACE_RUN_LEN {
+        insertion_sort_by(arr, &mut *compare);
+        return;
+    }
+
+    let mid = n / 2;
+    merge_sort_in_place_recursive(&mut arr[..mid], compare);
+    merge_sort_in_place_recursive(&mut arr[mid..], compare);
+
+    // 两半已经首尾有序时不需要合并
+    if compare(&arr[mid - 1], &arr[mid]).is_gt() {
+        sym_merge(arr, mid, compare);
+    }
+}
+
+/// 乒乓归并排序每个初始有序段的长度，先用插入排序排好
+const PING_PONG_RUN_LEN: usize = 16;
+
+/// 乒乓归并排序（自底向上），只分配一次 n 个元素的缓冲区
+///
+/// 每一趟把所有相邻的有序段从源数组合并到目标数组，然后交换两者的角色，
+/// 元素在原数组和缓冲区之间来回移动，不需要先把左半部分复制出来再合并。
+pub fn merge_sort_ping_pong<T: Ord>(arr: &mut [T]) {
+    merge_sort_ping_pong_by(arr, T::cmp);
+}
+
+/// 按指定方向排序的乒乓归并排序，降序时依然稳定
+pub fn merge_sort_ping_pong_with_order<T: Ord>(arr: &mut [T], order: Order) {
+    merge_sort_ping_pong_by(arr, |a, b| order.apply(a.cmp(b)));
+}
+
+/// 使用自定义比较函数的乒乓归并排序
+pub fn merge_sort_ping_pong_by<T, F>(arr: &mut [T], compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    merge_sort_with_buffer_by(arr, &mut Vec::new(), compare);
+}
+
+/// 按键排序的乒乓归并排序，比较 `key(a)` 与 `key(b)`
+pub fn merge_sort_ping_pong_by_key<T, K, F>(arr: &mut [T], mut key: F)
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    merge_sort_ping_pong_by(arr, |a, b| key(a).cmp(&key(b)));
+}
+
+/// 使用调用方提供的缓冲区的乒乓归并排序
+///
+/// `buf` 会先被清空，容量不足 `arr.len()` 时扩容；排序结束后 `buf` 为空但保留容量。
+/// 在循环中反复排序时传入同一个 `buf`，第一次之后就不再分配内存。
+///
+/// # 示例
+/// ```
+/// use sort::merge_sort::merge_sort_with_buffer;
+///
+/// let mut buf = Vec::new();
+/// for round in 0..3 {
+///     let mut arr: Vec<u32> = (0..100).map(|i| (i * 37 + round) % 101).collect();
+///     merge_sort_with_buffer(&mut arr, &mut buf);
+///     assert!(arr.windows(2).all(|w| w[0] <= w[1]));
+/// }
+/// assert!(buf.is_empty() && buf.capacity() >= 100);
+/// ```
+pub fn merge_sort_with_buffer<T: Ord>(arr: &mut [T], buf: &mut Vec<T>) {
+    merge_sort_with_buffer_by(arr, buf, T::cmp);
+}
+
+/// 使用调用方提供的缓冲区和自定义比较函数的乒乓归并排序
+pub fn merge_sort_with_buffer_by<T, F>(arr: &mut [T], buf: &mut Vec<T>, mut compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    buf.clear();
+    let n = arr.len();
+    if n <= 1 {
+        return;
+    }
+
+    buf.reserve(n);
+    ping_pong_merge_sort(arr, &mut buf.spare_capacity_mut()[..n], &mut compare);
+}
+
+/// 乒乓归并排序的核心，`buf` 的长度与 `arr` 相同，调用前后都不含有效元素
+fn ping_pong_merge_sort<T, F>(arr: &mut [T], buf: &mut [MaybeUninit<T>], compare: &mut F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let n = arr.len();
+    debug_assert_eq!(buf.len(), n);
+
+    // 先用插入排序得到长度为 PING_PONG_RUN_LEN 的有序段
+    for run in arr.chunks_mut(PING_PONG_RUN_LEN) {
+        insertion_sort_by(run, &mut *compare);
+    }
+    if n <= PING_PONG_RUN_LEN {
+        return;
+    }
+
+    let a = arr.as_mut_ptr();
+    let b = buf.as_mut_ptr() as *mut T;
+    let mut pass = PingPongPass {
+        arr: a,
+        len: n,
+        src: a,
+        dst: b,
+        out: 0,
+        left: 0,
+        mid: 0,
+        right: 0,
+        end: 0,
+    };
+
+    // SAFETY: 所有指针都在 `arr` 或 `buf` 的范围内，两者不重叠。`pass` 记录了
+    // 每个元素当前所在的位置，见 `PingPongPass`；析构时（包括 `compare` panic 时）
+    // 它会把所有元素移回 `arr`，每个元素恰好出现一次。
+    unsafe {
+        let mut width = PING_PONG_RUN_LEN;
+        while width < n {
+            let mut lo = 0;
+            while lo < n {
+                pass.left = lo;
+                pass.mid = (lo + width).min(n);
+                pass.right = pass.mid;
+                pass.end = (lo + 2 * width).min(n);
+
+                // 把 src[left..mid] 和 src[mid..end] 合并到 dst[lo..end]
+                while pass.left < pass.mid && pass.right < pass.end {
+                    // 两侧元素相等时优先取左侧元素，保证稳定性
+                    let left = pass.src.add(pass.left);
+                    let right = pass.src.add(pass.right);
+                    if compare(&*left, &*right).is_le() {
+                        ptr::copy_nonoverlapping(left, pass.dst.add(pass.out), 1);
+                        pass.left += 1;
+                    } else {
+                        ptr::copy_nonoverlapping(right, pass.dst.add(pass.out), 1);
+                        pass.right += 1;
+                    }
+                    pass.out += 1;
+                }
+                pass.flush_current();
+                lo = pass.end;
+            }
+
+            // 这一趟结束，所有元素都在 dst 中，交换源和目标
+            std::mem::swap(&mut pass.src, &mut pass.dst);
+            pass.out = 0;
+            pass.end = 0;
+            width *= 2;
+        }
+        // `pass` 析构时，如果结果在缓冲区中，把它移回 `arr`
+    }
+}
+
+/// 乒乓归并排序中一趟合并的进度
+///
+/// 任意时刻元素恰好分布在以下位置：`dst[..out]`（已合并）、`src[left..mid]` 和
+/// `src[right..end]`（正在合并的两段中尚未取出的部分）、`src[end..len]`（还没有开始合并）。
+struct PingPongPass<T> {
+    arr: *mut T,
+    len: usize,
+    src: *mut T,
+    dst: *mut T,
+    out: usize,
+    left: usize,
+    mid: usize,
+    right: usize,
+    end: usize,
+}
+
+impl<T> PingPongPass<T> {
+    /// 把正在合并的两段中剩余的元素按顺序移到 `dst[out..]`
+    unsafe fn flush_current(&mut self) {
+        let rest = self.mid - self.left;
+        ptr::copy_nonoverlapping(self.src.add(self.left), self.dst.add(self.out), rest);
+        self.out += rest;
+        self.left = self.mid;
+
+        let rest = self.end - self.right;
+        ptr::copy_nonoverlapping(self.src.add(self.right), self.dst.add(self.out), rest);
+        self.out += rest;
+        self.right = self.end;
+    }
+}
+
+impl<T> Drop for PingPongPass<T> {
+    fn drop(&mut self) {
+        // SAFETY: 按结构体上记录的分布移动元素，每个元素只移动一次
+        unsafe {
+            // 一趟还没有开始时所有元素都在 src 中，否则先把它们全部移到 dst
+            let holder = if self.out == 0 && self.end == 0 {
+                self.src
+            } else {
+                self.flush_current();
+                let rest = self.len - self.end;
+                ptr::copy_nonoverlapping(self.src.add(self.end), self.dst.add(self.end), rest);
+                self.dst
+            };
+            if holder != self.arr {
+                ptr::copy_nonoverlapping(holder, self.arr, self.len);
+            }
+        }
+    }
+}
+
+/// 并行归并排序，线程数取 [`Parallelism::default`]
+///
+/// 结果与 [`merge_sort`] 完全相同，相等的元素保持原有顺序。
+pub fn merge_sort_parallel<T: Ord + Send>(arr: &mut [T]) {
+    merge_sort_parallel_by(arr, T::cmp);
+}
+
+/// 按指定方向排序的并行归并排序，降序时依然稳定
+pub fn merge_sort_parallel_with_order<T: Ord + Send>(arr: &mut [T], order: Order) {
+    merge_sort_parallel_by(arr, |a, b| order.apply(a.cmp(b)));
+}
+
+/// 使用自定义比较函数的并行归并排序
+///
+/// `compare` 会在多个线程中同时调用，所以要求 `Fn + Sync` 而不是 `FnMut`。
+pub fn merge_sort_parallel_by<T, F>(arr: &mut [T], compare: F)
+where
+    T: Send,
+    F: Fn(&T, &T) -> Ordering + Sync,
+{
+    merge_sort_with_parallelism_by(arr, Parallelism::default(), compare);
+}
+
+/// 按键排序的并行归并排序，比较 `key(a)` 与 `key(b)`
+pub fn merge_sort_parallel_by_key<T, K, F>(arr: &mut [T], key: F)
+where
+    T: Send,
+    K: Ord,
+    F: Fn(&T) -> K + Sync,
+{
+    merge_sort_parallel_by(arr, |a, b| key(a).cmp(&key(b)));
+}
+
+/// 指定线程数和顺序排序阈值的并行归并排序
+///
+/// # 示例
+/// ```
+/// use sort::merge_sort::merge_sort_with_parallelism;
+/// use sort::Parallelism;
+///
+/// let mut arr: Vec<u32> = (0..100_000).map(|i| (i * 7919) % 100_003).collect();
+/// merge_sort_with_parallelism(&mut arr, Parallelism::new(4).with_sequential_cutoff(4096));
+/// assert!(arr.windows(2).all(|w| w[0] <= w[1]));
+/// ```
+pub fn merge_sort_with_parallelism<T: Ord + Send>(arr: &mut [T], parallelism: Parallelism) {
+    merge_sort_with_parallelism_by(arr, parallelism, T::cmp);
+}
+
+/// 指定线程数、顺序排序阈值和比较函数的并行归并排序
+///
+/// 1. 把数组分成最多 `threads` 段，每段不短于 `sequential_cutoff`，各线程分别用 [`merge_sort_by`] 排序
+/// 2. 相邻两段两两合并，每一趟在原数组和缓冲区之间来回。每次合并都把输出按位置均分：
+///    对输出位置 k 二分查找出前 k 个输出中有几个来自左段（merge path），
+///    各块对应的左右两段输入互不重叠，可以由不同线程独立合并
+///
+/// 只在调用方线程中做二分查找，各线程只访问自己那一块，所以元素只需要 `Send`。
+pub fn merge_sort_with_parallelism_by<T, F>(arr: &mut [T], parallelism: Parallelism, compare: F)
+where
+    T: Send,
+    F: Fn(&T, &T) -> Ordering + Sync,
+{
+    let n = arr.len();
+    let threads = parallelism.threads.max(1);
+    let cutoff = parallelism.sequential_cutoff.max(1);
+    if threads == 1 || n <= cutoff {
+        merge_sort_by(arr, &compare);
+        return;
+    }
+
+    // 第一步：各线程分别排序一段，调用方线程负责第一段
+    let run_len = n.div_ceil(threads.min(n.div_ceil(cutoff)));
+    thread::scope(|s| {
+        let mut runs = arr.chunks_mut(run_len);
+        let first = runs.next().unwrap();
+        for run in runs {
+            let compare = &compare;
+            s.spawn(move || merge_sort_by(run, compare));
+        }
+        merge_sort_by(first, &compare);
+    });
+
+    // 第二步：逐趟并行合并，每个线程负责大约 `piece` 个输出
+    let mut buf: Vec<T> = Vec::with_capacity(n);
+    // 两个字段必须来自同一个指针，再次调用 `as_mut_ptr` 会使之前取得的指针失效
+    let base = arr.as_mut_ptr();
+    let mut pass = ParallelPass {
+        arr: base,
+        src: base,
+        len: n,
+    };
+    let mut dst = buf.as_mut_ptr();
+    let piece = n.div_ceil(threads);
+    let mut width = run_len;
+    while width < n {
+        // SAFETY: `src` 与 `dst` 分别是 `arr` 和 `buf`（或相反），各有 `n` 个位置且不重叠。
+        // 这一趟只把元素按位复制到 `dst`，`src` 保持不变；全部完成后才交换两者，
+        // 中途 panic 时 `pass` 析构，把仍然完整的 `src` 移回 `arr`。
+        unsafe { parallel_merge_pass(pass.src, dst, n, width, piece, &compare) };
+        mem::swap(&mut pass.src, &mut dst);
+        width *= 2;
+    }
+    // `pass` 析构时，如果结果在缓冲区中，把它移回 `arr`
+}
+
+/// 把 `src` 中相邻的有序段 `[lo, lo + width)` 和 `[lo + width, lo + 2 * width)`
+/// 合并到 `dst` 的相同位置
+///
+/// 输出按全局位置每 `piece` 个切成一组，每组由一个线程负责，其中可能包含几对有序段的片段。
+/// 最后一组在调用方线程中合并，所以最多新建 `len.div_ceil(piece) - 1` 个线程。
+///
+/// # Safety
+/// `src` 和 `dst` 各有 `len` 个位置且不重叠，`src` 中的元素全部有效。
+/// 元素只是按位复制到 `dst`，调用方负责决定之后哪一边持有它们。
+unsafe fn parallel_merge_pass<T, F>(
+    src: *mut T,
+    dst: *mut T,
+    len: usize,
+    width: usize,
+    piece: usize,
+    compare: &F,
+) where
+    T: Send,
+    F: Fn(&T, &T) -> Ordering + Sync,
+{
+    type Task<'a, T> = (&'a mut [T], &'a mut [T], &'a mut [MaybeUninit<T>]);
+    let mut groups: Vec<Vec<Task<'_, T>>> = (0..len.div_ceil(piece)).map(|_| Vec::new()).collect();
+
+    let mut lo = 0;
+    while lo < len {
+        let mid = (lo + width).min(len);
+        let end = (lo + 2 * width).min(len);
+        let mut left = slice::from_raw_parts_mut(src.add(lo), mid - lo);
+        let mut right = slice::from_raw_parts_mut(src.add(mid), end - mid);
+        let mut out = slice::from_raw_parts_mut(dst.add(lo) as *mut MaybeUninit<T>, end - lo);
+
+        // 在这一对有序段内部的组边界处切开：每块的终点 k 以及前 k 个输出中来自左段的个数
+        let first = (lo / piece + 1) * piece;
+        let mut bounds: Vec<(usize, usize)> = (first..end)
+            .step_by(piece)
+            .map(|g| (g - lo, merge_path_split(left, right, g - lo, compare)))
+            .collect();
+        bounds.push((end - lo, mid - lo));
+
+        let (mut prev_k, mut prev_i) = (0, 0);
+        for (k, i) in bounds {
+            let a = mem::take(&mut left).split_at_mut(i - prev_i);
+            let b = mem::take(&mut right).split_at_mut((k - i) - (prev_k - prev_i));
+            let o = mem::take(&mut out).split_at_mut(k - prev_k);
+            (left, right, out) = (a.1, b.1, o.1);
+            groups[(lo + prev_k) / piece].push((a.0, b.0, o.0));
+            (prev_k, prev_i) = (k, i);
+        }
+        lo = end;
+    }
+
+    let merge_group = move |group: Vec<Task<'_, T>>| {
+        for (a, b, o) in group {
+            merge_into(a, b, o, compare);
+        }
+    };
+    thread::scope(|s| {
+        let last = groups.pop().unwrap();
+        for group in groups {
+            s.spawn(move || merge_group(group));
+        }
+        merge_group(last);
+    });
+}
+
+/// merge path：稳定合并 `left` 和 `right` 时，前 `k` 个输出中有几个来自 `left`
+fn merge_path_split<T, F>(left: &[T], right: &[T], k: usize, compare: &F) -> usize
+where
+    F: Fn(&T, &T) -> Ordering,
+{
+    let mut lo = k.saturating_sub(right.len());
+    let mut hi = k.min(left.len());
+    while lo < hi {
+        let m = lo + (hi - lo) / 2;
+        // `left[m]` 不大于 `right[k - m - 1]` 时排在它前面，也在前 k 个输出之中
+        if compare(&left[m], &right[k - m - 1]).is_le() {
+            lo = m + 1;
+        } else {
+            hi = m;
+        }
+    }
+    lo
+}
+
+/// 把有序的 `left` 和 `right` 按位复制合并到 `out`，两侧元素相等时优先取左侧元素
+///
+/// 输入保持不变，`compare` panic 时 `out` 中已复制的部分直接丢弃即可。
+fn merge_into<T, F>(left: &[T], right: &[T], out: &mut [MaybeUninit<T>], compare: &F)
+where
+    F: Fn(&T, &T) -> Ordering,
+{
+    debug_assert_eq!(left.len() + right.len(), out.len());
+    let out = out.as_mut_ptr() as *mut T;
+    let (mut i, mut j) = (0, 0);
+    // SAFETY: `out` 正好能容纳两侧全部元素，每个位置只写一次
+    unsafe {
+        while i < left.len() && j < right.len() {
+            if compare(&left[i], &right[j]).is_le() {
+                ptr::copy_nonoverlapping(&left[i], out.add(i + j), 1);
+                i += 1;
+            } else {
+                ptr::copy_nonoverlapping(&right[j], out.add(i + j), 1);
+                j += 1;
+            }
+        }
+        ptr::copy_nonoverlapping(left[i..].as_ptr(), out.add(i + j), left.len() - i);
+        ptr::copy_nonoverlapping(
+            right[j..].as_ptr(),
+            out.add(left.len() + j),
+            right.len() - j,
+        );
+    }
+}
+
+/// 并行合并中持有全部元素的一侧，析构时（包括 panic 时）把它们移回 `arr`
+struct ParallelPass<T> {
+    arr: *mut T,
+    src: *mut T,
+    len: usize,
+}
+
+impl<T> Drop for ParallelPass<T> {
+    fn drop(&mut self) {
+        if self.src != self.arr {
+            // SAFETY: `src` 是与 `arr` 不重叠的缓冲区，其中 `len` 个元素全部有效
+            unsafe { ptr::copy_nonoverlapping(self.src, self.arr, self.len) };
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{assert_each_once, random_vec, tracked_vec, Tracked};
+    use std::panic::{self, AssertUnwindSafe};
+
+    #[test]
+    fn test_merge_sort() {
+        let mut arr = vec![38, 27, 43, 3, 9, 82, 10];
+        merge_sort(&mut arr);
+        assert_eq!(arr, vec![3, 9, 10, 27, 38, 43, 82]);
+    }
+
+    #[test]
+    fn test_merge_sort_iterative() {
+        let mut arr = vec![38, 27, 43, 3, 9, 82, 10];
+        merge_sort_iterative(&mut arr);
+        assert_eq!(arr, vec![3, 9, 10, 27, 38, 43, 82]);
+    }
+
+    #[test]
+    fn test_empty() {
+        let mut arr: Vec<i32> = vec![];
+        merge_sort(&mut arr);
+        assert_eq!(arr, vec![]);
+    }
+
+    #[test]
+    fn test_single() {
+        let mut arr = vec![42];
+        merge_sort(&mut arr);
+        assert_eq!(arr, vec![42]);
+    }
+
+    #[test]
+    fn test_duplicates() {
+        let mut arr = vec![3, 1, 4, 1, 5, 9, 2, 6, 5];
+        merge_sort(&mut arr);
+        assert_eq!(arr, vec![1, 1, 2, 3, 4, 5, 5, 6, 9]);
+    }
+
+    #[test]
+    fn test_merge_sort_by_stability() {
+        // 只按长度排序，长度相同的字符串保持原有顺序
+        let words = vec!["pear", "fig", "plum", "kiwi", "date", "yam"];
+        let expected = vec!["fig", "yam", "pear", "plum", "kiwi", "date"];
+
+        let mut arr = words.clone();
+        merge_sort_by(&mut arr, |a, b| a.len().cmp(&b.len()));
+        assert_eq!(arr, expected);
+
+        let mut arr = words.clone();
+        merge_sort_iterative_by(&mut arr, |a, b| a.len().cmp(&b.len()));
+        assert_eq!(arr, expected);
+    }
+
+    #[test]
+    fn test_merge_sort_by_key() {
+        let mut people = vec![("Carol", 35), ("Alice", 30), ("Bob", 25), ("Dave", 30)];
+        merge_sort_by_key(&mut people, |p| p.1);
+        assert_eq!(
+            people,
+            vec![("Bob", 25), ("Alice", 30), ("Dave", 30), ("Carol", 35)]
+        );
+    }
+
+    #[test]
+    fn test_merge_sort_by_cached_key() {
+        let mut dates = vec!["2024-03-01", "2023-12-25", "2024-01-15"];
+        merge_sort_by_cached_key(&mut dates, |d| {
+            d.split('-')
+                .map(|part| part.parse::<u32>().unwrap())
+                .collect::<Vec<_>>()
+        });
+        assert_eq!(dates, vec!["2023-12-25", "2024-01-15", "2024-03-01"]);
+    }
+
+    #[test]
+    fn test_moves_without_clone() {
+        let keys = random_vec(300, 30, 21);
+        for sort in [
+            merge_sort::<Tracked>,
+            merge_sort_iterative::<Tracked>,
+            merge_sort_in_place::<Tracked>,
+        ] {
+            let (mut arr, drops) = tracked_vec(&keys);
+            sort(&mut arr);
+            // 按 key 排序且 key 相同时 id 升序，说明结果正确并且稳定
+            assert!(arr
+                .windows(2)
+                .all(|w| (w[0].key, w[0].id) < (w[1].key, w[1].id)));
+            assert_each_once(arr, &drops);
+        }
+
+        let mut words: Vec<Box<str>> = vec!["pear".into(), "fig".into(), "apple".into()];
+        merge_sort(&mut words);
+        assert_eq!(words, vec!["apple".into(), "fig".into(), "pear".into()]);
+    }
+
+    #[test]
+    fn test_zero_sized_elements() {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+        struct Zst;
+
+        for sort in [
+            merge_sort::<Zst>,
+            merge_sort_iterative::<Zst>,
+            merge_sort_in_place::<Zst>,
+            merge_sort_ping_pong::<Zst>,
+        ] {
+            let mut arr = vec![Zst; 300];
+            sort(&mut arr);
+            assert_eq!(arr.len(), 300);
+        }
+        let parallelism = Parallelism::new(4).with_sequential_cutoff(16);
+        let mut arr = vec![Zst; 300];
+        merge_sort_with_parallelism(&mut arr, parallelism);
+        assert_eq!(arr.len(), 300);
+
+        // 比较结果不一致时也不能越界或重复释放
+        let mut state = 0x9E37_79B9_7F4A_7C15u64;
+        let mut compare = |_: &Zst, _: &Zst| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            if state & 1 == 0 {
+                Ordering::Less
+            } else {
+                Ordering::Greater
+            }
+        };
+        let mut arr = vec![Zst; 300];
+        merge_sort_by(&mut arr, &mut compare);
+        merge_sort_iterative_by(&mut arr, &mut compare);
+        merge_sort_in_place_by(&mut arr, &mut compare);
+        merge_sort_ping_pong_by(&mut arr, &mut compare);
+        assert_eq!(arr.len(), 300);
+    }
+
+    #[test]
+    fn test_panic_in_compare_keeps_every_element() {
+        for iterative in [false, true] {
+            let (mut arr, drops) = tracked_vec(&random_vec(100, 1000, 22));
+            let mut calls = 0;
+            let compare = |a: &Tracked, b: &Tracked| {
+                calls += 1;
+                assert!(calls < 400, "比较函数 panic");
+                a.cmp(b)
+            };
+            let result = panic::catch_unwind(AssertUnwindSafe(|| {
+                if iterative {
+                    merge_sort_iterative_by(&mut arr, compare)
+                } else {
+                    merge_sort_by(&mut arr, compare)
+                }
+            }));
+            assert!(result.is_err());
+            assert_each_once(arr, &drops);
+        }
+    }
+
+    #[test]
+    fn test_merge_in_place() {
+        // 覆盖任意一侧为空、只有一个元素以及两侧长度悬殊的情况
+        for len in 0..40 {
+            let keys = random_vec(len, 8, len as u64 + 31);
+            for mid in 0..=len {
+                let mut arr: Vec<(u64, usize)> = keys.iter().copied().zip(0..).collect();
+                arr[..mid].sort();
+                arr[mid..].sort();
+                merge_in_place_by(&mut arr, mid, |a, b| a.0.cmp(&b.0));
+                // 键相同时左侧元素在前，左右两侧内部也保持原有顺序
+                let mut expected = keys.iter().copied().zip(0..).collect::<Vec<_>>();
+                expected[..mid].sort();
+                expected[mid..].sort();
+                expected.sort_by_key(|&(key, _)| key);
+                assert_eq!(arr, expected, "len = {}, mid = {}", len, mid);
+            }
+        }
+
+        let mut arr = vec![1, 4, 6, 9, 2, 3, 7];
+        merge_in_place(&mut arr, 4);
+        assert_eq!(arr, vec![1, 2, 3, 4, 6, 7, 9]);
+    }
+
+    #[test]
+    fn test_merge_sort_in_place() {
+        for len in (0..100).chain([1000, 4321]) {
+            let input = random_vec(len, 50, len as u64 + 32);
+            let mut expected = input.clone();
+            expected.sort();
+
+            let mut arr = input.clone();
+            merge_sort_in_place(&mut arr);
+            assert_eq!(arr, expected, "len = {}", len);
+
+            let mut arr = input;
+            merge_sort_in_place_with_order(&mut arr, Order::Descending);
+            expected.reverse();
+            assert_eq!(arr, expected, "len = {}", len);
+        }
+
+        let mut people = vec![("Carol", 35), ("Alice", 30), ("Bob", 25), ("Dave", 30)];
+        merge_sort_in_place_by_key(&mut people, |p| p.1);
+        assert_eq!(
+            people,
+            vec![("Bob", 25), ("Alice", 30), ("Dave", 30), ("Carol", 35)]
+        );
+    }
+
+    #[test]
+    fn test_in_place_panic_keeps_every_element() {
+        let keys = random_vec(500, 1000, 33);
+        for limit in [1, 100, 2000] {
+            let (mut arr, drops) = tracked_vec(&keys);
+            let mut calls = 0;
+            let result = panic::catch_unwind(AssertUnwindSafe(|| {
+                merge_sort_in_place_by(&mut arr, |a: &Tracked, b: &Tracked| {
+                    calls += 1;
+                    assert!(calls < limit, "比较函数 panic");
+                    a.cmp(b)
+                })
+            }));
+            assert!(result.is_err());
+            assert_each_once(arr, &drops);
+        }
+    }
+
+    #[test]
+    fn test_ping_pong() {
+        // 覆盖比初始有序段短、正好一段、结果停在缓冲区等各种长度
+        for len in [0, 1, 2, 15, 16, 17, 33, 64, 100, 257, 1000] {
+            let input = random_vec(len, 50, len as u64 + 1);
+            let mut expected = input.clone();
+            expected.sort();
+
+            let mut arr = input.clone();
+            merge_sort_ping_pong(&mut arr);
+            assert_eq!(arr, expected, "len = {}", len);
+
+            let mut arr = input.clone();
+            merge_sort_ping_pong_with_order(&mut arr, Order::Descending);
+            expected.reverse();
+            assert_eq!(arr, expected, "len = {}", len);
+        }
+    }
+
+    #[test]
+    fn test_ping_pong_is_stable() {
+        let keys = random_vec(500, 20, 61);
+        let (mut arr, drops) = tracked_vec(&keys);
+        merge_sort_ping_pong(&mut arr);
+        assert!(arr
+            .windows(2)
+            .all(|w| (w[0].key, w[0].id) < (w[1].key, w[1].id)));
+        assert_each_once(arr, &drops);
+    }
+
+    #[test]
+    fn test_with_buffer_reuses_allocation() {
+        let mut buf = Vec::new();
+        let mut arr: Vec<u64> = random_vec(300, 1000, 62);
+        merge_sort_with_buffer(&mut arr, &mut buf);
+        assert!(arr.windows(2).all(|w| w[0] <= w[1]));
+        assert!(buf.is_empty());
+
+        let capacity = buf.capacity();
+        let ptr = buf.as_ptr();
+        for seed in 0..5 {
+            let mut arr = random_vec(300, 1000, seed + 63);
+            merge_sort_with_buffer_by(&mut arr, &mut buf, |a, b| b.cmp(a));
+            assert!(arr.windows(2).all(|w| w[0] >= w[1]));
+            // 长度不变时不会重新分配
+            assert_eq!((buf.capacity(), buf.as_ptr()), (capacity, ptr));
+        }
+    }
+
+    #[test]
+    fn test_with_buffer_clears_short_input() {
+        // 不需要排序的数组也会先清空 buf
+        for len in [0, 1] {
+            let mut buf = vec![5u64, 3, 8];
+            let mut arr = random_vec(len, 1000, 65);
+            merge_sort_with_buffer(&mut arr, &mut buf);
+            assert!(buf.is_empty(), "len = {}", len);
+            assert!(buf.capacity() >= 3);
+        }
+    }
+
+    #[test]
+    fn test_ping_pong_panic_keeps_every_element() {
+        // 在不同的趟数中 panic，元素分别停留在原数组或缓冲区
+        for limit in [10, 700, 1500, 2500] {
+            let (mut arr, drops) = tracked_vec(&random_vec(300, 1000, 64));
+            let mut calls = 0;
+            let result = panic::catch_unwind(AssertUnwindSafe(|| {
+                merge_sort_ping_pong_by(&mut arr, |a, b| {
+                    calls += 1;
+                    assert!(calls < limit, "比较函数 panic");
+                    a.cmp(b)
+                })
+            }));
+            assert!(result.is_err(), "limit = {}", limit);
+            assert_each_once(arr, &drops);
+        }
+    }
+
+    #[test]
+    fn test_parallel_matches_merge_sort() {
+        for threads in [1, 2, 3, 4, 7] {
+            let parallelism = Parallelism::new(threads).with_sequential_cutoff(64);
+            for len in [0, 1, 64, 65, 100, 1000, 4321, 20_000] {
+                let keys = random_vec(len, 100, len as u64 + threads as u64);
+                let input: Vec<(u64, usize)> = keys.into_iter().zip(0..).collect();
+
+                let mut expected = input.clone();
+                merge_sort_by(&mut expected, |a, b| a.0.cmp(&b.0));
+                let mut arr = input;
+                merge_sort_with_parallelism_by(&mut arr, parallelism, |a, b| a.0.cmp(&b.0));
+                assert_eq!(arr, expected, "threads = {}, len = {}", threads, len);
+            }
+        }
+
+        let input = random_vec(200_000, 1_000_000, 34);
+        let mut expected = input.clone();
+        expected.sort();
+        let mut arr = input.clone();
+        merge_sort_parallel(&mut arr);
+        assert_eq!(arr, expected);
+
+        let mut arr = input;
+        merge_sort_parallel_with_order(&mut arr, Order::Descending);
+        expected.reverse();
+        assert_eq!(arr, expected);
+    }
+
+    #[test]
+    fn test_parallel_panic_keeps_every_element() {
+        // `Tracked` 不能跨线程，这里用 `Box` 检查元素既没有丢失也没有重复
+        let keys = random_vec(5000, 1000, 35);
+        let parallelism = Parallelism::new(4).with_sequential_cutoff(100);
+        let calls = std::sync::atomic::AtomicUsize::new(0);
+        let mut arr = keys.clone();
+        merge_sort_with_parallelism_by(&mut arr, parallelism, |a, b| {
+            calls.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+            a.cmp(b)
+        });
+        // 最后一个上限落在最后一趟并行合并中
+        let total = calls.into_inner();
+        for limit in [1, 1000, total / 2, total - 100] {
+            let mut arr: Vec<Box<(u64, usize)>> =
+                keys.iter().copied().zip(0..).map(Box::new).collect();
+            let calls = std::sync::atomic::AtomicUsize::new(0);
+            let result = panic::catch_unwind(AssertUnwindSafe(|| {
+                merge_sort_with_parallelism_by(&mut arr, parallelism, |a, b| {
+                    let calls = calls.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+                    assert!(calls < limit, "比较函数 panic");
+                    a.0.cmp(&b.0)
+                })
+            }));
+            assert!(result.is_err(), "limit = {}", limit);
+            let mut ids: Vec<usize> = arr.iter().map(|item| item.1).collect();
+            ids.sort();
+            assert!(ids.into_iter().eq(0..keys.len()));
+        }
+    }
+}
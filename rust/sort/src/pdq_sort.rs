@@ -0,0 +1,449 @@
+//! 模式消除快速排序 (Pattern-defeating Quicksort, pdqsort)
+//!
+//! # 算法原理
+//! pdqsort 在内省排序的基础上针对常见的输入模式做了优化：
+//! 1. 选择基准：短区间用三数取中，长区间用九数取中（ninther）；
+//!    取样时如果发现样本完全有序，说明整个区间可能已经有序
+//! 2. 区间可能有序且上一次分区均衡、没有发生交换时，尝试有限步数的插入排序，
+//!    成功就直接结束，所以有序、逆序输入只需要 O(n) 次比较
+//! 3. 基准与上一层的基准相等时，把等于基准的元素都放到左侧，之后不再处理，
+//!    大量重复元素时接近 O(n)（与三路快排的思路相同）
+//! 4. 分区严重失衡时，随机交换几个元素打乱输入中的模式；失衡次数过多时改用堆排序
+//! 5. 分区使用分块分区（BlockQuicksort），比较结果不影响分支，随机数据上没有分支预测失败
+//! 6. 只对较小的一侧递归，区间足够小时用插入排序收尾
+//!
+//! # 与其他快速排序的比较
+//! - 有序、逆序、锯齿形和大量重复元素的输入上，比较次数都少于 `quick_sort`、`quick_sort_hoare`
+//! - 有序、逆序输入上比较次数也远少于 `quick_sort_3way`
+//! - 锯齿形和只有少数几种取值的输入上，比较次数多于 `quick_sort_3way`（不超过两倍）：
+//!   三路快排一次比较就能区分小于、等于、大于，而这里的二路分区需要两次。
+//!   取值只有几种时三路快排通常也更快
+//!
+//! # 时间复杂度
+//! - 最好: O(n) - 有序、逆序、全部相等
+//! - 平均/最坏: O(n log n)
+//!
+//! # 空间复杂度: O(log n) - 递归栈空间
+//! # 稳定性: 不稳定
+
+use crate::heap_sort::heap_sort_iterative_by;
+use crate::insertion_sort::{insert_tail_by, insertion_sort_by};
+use crate::quick_sort::partition_in_blocks;
+use crate::Order;
+use std::cmp::Ordering;
+
+/// 区间长度不超过该值时用插入排序
+const MAX_INSERTION: usize = 20;
+
+/// 区间长度不小于该值时用九数取中选择基准
+const SHORTEST_NINTHER: usize = 50;
+
+/// 选择基准时交换次数达到该值，说明区间可能是逆序的
+const MAX_PIVOT_SWAPS: usize = 4 * 3;
+
+/// 尝试插入排序时最多修正的逆序对个数
+const MAX_PARTIAL_STEPS: usize = 5;
+
+/// 区间长度小于该值时不尝试插入排序
+const SHORTEST_SHIFTING: usize = 50;
+
+/// pdqsort 主函数
+pub fn pdq_sort<T: Ord>(arr: &mut [T]) {
+    pdq_sort_by(arr, T::cmp);
+}
+
+/// 按指定方向排序的 pdqsort
+pub fn pdq_sort_with_order<T: Ord>(arr: &mut [T], order: Order) {
+    pdq_sort_by(arr, |a, b| order.apply(a.cmp(b)));
+}
+
+/// 使用自定义比较函数的 pdqsort
+pub fn pdq_sort_by<T, F>(arr: &mut [T], mut compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    // 允许失衡的次数，超过后改用堆排序
+    let limit = usize::BITS - arr.len().leading_zeros();
+    pdq_recursive(arr, &mut compare, None, limit);
+}
+
+/// 按键排序的 pdqsort，比较 `key(a)` 与 `key(b)`
+pub fn pdq_sort_by_key<T, K, F>(arr: &mut [T], mut key: F)
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    pdq_sort_by(arr, |a, b| key(a).cmp(&key(b)));
+}
+
+/// 排序 `arr`，`pred` 是紧挨在 `arr` 左侧的上一层基准，`arr` 中的元素都不小于它
+fn pdq_recursive<'a, T, F>(
+    mut arr: &'a mut [T],
+    compare: &mut F,
+    mut pred: Option<&'a T>,
+    mut limit: u32,
+) where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let mut was_balanced = true;
+    let mut was_partitioned = true;
+
+    loop {
+        let len = arr.len();
+
+        if len <= MAX_INSERTION {
+            insertion_sort_by(arr, &mut *compare);
+            return;
+        }
+
+        // 失衡次数过多，改用堆排序保证 O(n log n)
+        if limit == 0 {
+            heap_sort_iterative_by(arr, &mut *compare);
+            return;
+        }
+
+        // 上一次分区失衡，打乱一些元素，避免同样的模式继续造成失衡
+        if !was_balanced {
+            break_patterns(arr);
+            limit -= 1;
+        }
+
+        let (pivot, likely_sorted) = choose_pivot(arr, compare);
+
+        // 上一次分区均衡且没有交换，取样也是有序的：区间很可能已经有序
+        if was_balanced && was_partitioned && likely_sorted && partial_insertion_sort(arr, compare)
+        {
+            return;
+        }
+
+        // 基准等于上一层的基准，说明它是区间中的最小值，
+        // 把所有等于它的元素放到左侧，只需要继续处理右侧
+        if let Some(p) = pred {
+            if !compare(p, &arr[pivot]).is_lt() {
+                let mid = partition_equal(arr, pivot, compare);
+                arr = &mut arr[mid..];
+                continue;
+            }
+        }
+
+        let (mid, partitioned) = partition(arr, pivot, compare);
+        was_balanced = mid.min(len - mid) >= len / 8;
+        was_partitioned = partitioned;
+
+        let (left, right) = arr.split_at_mut(mid);
+        let (pivot, right) = right.split_at_mut(1);
+        let pivot = &pivot[0];
+
+        // 只递归较小的一侧，较大的一侧留给下一轮循环
+        if left.len() < right.len() {
+            pdq_recursive(left, compare, pred, limit);
+            arr = right;
+            pred = Some(pivot);
+        } else {
+            pdq_recursive(right, compare, Some(pivot), limit);
+            arr = left;
+        }
+    }
+}
+
+/// 以 `arr[pivot]` 为基准分区
+///
+/// 返回 `(mid, was_partitioned)`：`arr[..mid]` 都小于基准，`arr[mid]` 是基准，
+/// `arr[mid + 1..]` 都不小于基准；`was_partitioned` 表示分区前就已经分好，没有交换任何元素。
+fn partition<T, F>(arr: &mut [T], pivot: usize, compare: &mut F) -> (usize, bool)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    arr.swap(0, pivot);
+    let (head, rest) = arr.split_at_mut(1);
+    let pivot = &head[0];
+
+    // 先跳过两端已经在正确一侧的元素：rest[..l] 都小于pivot，rest[r..] 都不小于pivot
+    let mut l = 0;
+    let mut r = rest.len();
+    while l < r && compare(&rest[l], pivot).is_lt() {
+        l += 1;
+    }
+    while l < r && !compare(&rest[r - 1], pivot).is_lt() {
+        r -= 1;
+    }
+    let was_partitioned = l >= r;
+
+    // 中间部分用分块分区，左侧不小于基准、右侧小于基准的元素算放错
+    l += partition_in_blocks(&mut rest[l..r], |x, in_left| {
+        compare(x, pivot).is_lt() != in_left
+    });
+
+    arr.swap(0, l);
+    (l, was_partitioned)
+}
+
+/// 以 `arr[pivot]` 为基准，把等于基准的元素放到左侧
+///
+/// 调用前 `arr` 中没有小于基准的元素。返回等于基准的元素个数 `mid`，
+/// `arr[..mid]` 都等于基准，`arr[mid..]` 都大于基准。
+fn partition_equal<T, F>(arr: &mut [T], pivot: usize, compare: &mut F) -> usize
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    arr.swap(0, pivot);
+    let (head, rest) = arr.split_at_mut(1);
+    let pivot = &head[0];
+
+    let mut l = 0;
+    let mut r = rest.len();
+
+    loop {
+        while l < r && !compare(pivot, &rest[l]).is_lt() {
+            l += 1;
+        }
+        while l < r && compare(pivot, &rest[r - 1]).is_lt() {
+            r -= 1;
+        }
+        if l >= r {
+            break;
+        }
+        r -= 1;
+        rest.swap(l, r);
+        l += 1;
+    }
+
+    // 加上基准本身
+    l + 1
+}
+
+/// 选择基准，返回 `(基准下标, 区间可能已经有序)`
+///
+/// 取 1/4、2/4、3/4 处的三个元素（长区间取这三处各自与相邻元素的中位数，即九数取中），
+/// 只交换下标而不移动元素。交换次数为 0 说明样本有序；交换次数很多说明样本逆序，
+/// 这时把整个区间反转，之后很可能只需一次插入排序就能完成。
+fn choose_pivot<T, F>(arr: &mut [T], compare: &mut F) -> (usize, bool)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let len = arr.len();
+    let mut a = len / 4;
+    let mut b = len / 4 * 2;
+    let mut c = len / 4 * 3;
+    let mut swaps = 0;
+
+    if len >= 8 {
+        let mut sort2 = |a: &mut usize, b: &mut usize| {
+            if compare(&arr[*b], &arr[*a]).is_lt() {
+                std::mem::swap(a, b);
+                swaps += 1;
+            }
+        };
+        let mut sort3 = |a: &mut usize, b: &mut usize, c: &mut usize| {
+            sort2(a, b);
+            sort2(b, c);
+            sort2(a, b);
+        };
+
+        if len >= SHORTEST_NINTHER {
+            // 九数取中：先求每个位置与两侧相邻元素的中位数
+            let mut sort_adjacent = |m: &mut usize| {
+                let (mut lo, mut hi) = (*m - 1, *m + 1);
+                sort3(&mut lo, m, &mut hi);
+            };
+            sort_adjacent(&mut a);
+            sort_adjacent(&mut b);
+            sort_adjacent(&mut c);
+        }
+
+        sort3(&mut a, &mut b, &mut c);
+    }
+
+    if swaps < MAX_PIVOT_SWAPS {
+        (b, swaps == 0)
+    } else {
+        // 样本几乎完全逆序，反转后基准下标也随之镜像
+        arr.reverse();
+        (len - 1 - b, true)
+    }
+}
+
+/// 尝试用插入排序完成一个几乎有序的区间
+///
+/// 最多修正 `MAX_PARTIAL_STEPS` 处逆序，全部修正后区间有序时返回 `true`。
+fn partial_insertion_sort<T, F>(arr: &mut [T], compare: &mut F) -> bool
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let len = arr.len();
+    let mut i = 1;
+
+    for _ in 0..MAX_PARTIAL_STEPS {
+        // 找到下一对逆序的相邻元素
+        while i < len && !compare(&arr[i], &arr[i - 1]).is_lt() {
+            i += 1;
+        }
+
+        if i == len {
+            return true;
+        }
+
+        // 区间较短时直接返回，修正的代价不划算
+        if len < SHORTEST_SHIFTING {
+            return false;
+        }
+
+        // 交换这对元素，再把较小的向左、较大的向右移到正确位置
+        arr.swap(i - 1, i);
+        if i >= 2 {
+            insert_tail_by(&mut arr[..i], i - 1, 1, compare);
+        }
+        let mut j = i;
+        while j + 1 < len && compare(&arr[j + 1], &arr[j]).is_lt() {
+            arr.swap(j, j + 1);
+            j += 1;
+        }
+    }
+
+    false
+}
+
+/// 用伪随机位置交换区间中部的三个元素，打乱会让基准选择持续失衡的模式
+fn break_patterns<T>(arr: &mut [T]) {
+    let len = arr.len();
+    if len < 8 {
+        return;
+    }
+
+    // xorshift，种子取区间长度，保证结果可复现
+    let mut seed = len as u64;
+    let mut next = || {
+        seed ^= seed << 13;
+        seed ^= seed >> 7;
+        seed ^= seed << 17;
+        seed
+    };
+
+    let mask = len.next_power_of_two() as u64 - 1;
+    let pos = len / 4 * 2;
+    for i in 0..3 {
+        let mut other = (next() & mask) as usize;
+        if other >= len {
+            other -= len;
+        }
+        arr.swap(pos - 1 + i, other);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::quick_sort::{quick_sort_3way_by, quick_sort_by, quick_sort_hoare_by};
+    use crate::test_util::random_vec;
+
+    type SortFn = fn(&mut [u64], &mut dyn FnMut(&u64, &u64) -> Ordering);
+
+    /// 用 `sort` 排序并返回比较次数
+    fn count_comparisons(input: &[u64], sort: SortFn) -> usize {
+        let mut arr = input.to_vec();
+        let mut calls = 0;
+        sort(&mut arr, &mut |a: &u64, b: &u64| {
+            calls += 1;
+            a.cmp(b)
+        });
+        assert!(arr.windows(2).all(|w| w[0] <= w[1]));
+        calls
+    }
+
+    /// 测试用的几种输入模式
+    fn patterns(n: usize) -> Vec<(&'static str, Vec<u64>)> {
+        vec![
+            ("sorted", (0..n as u64).collect()),
+            ("reversed", (0..n as u64).rev().collect()),
+            ("sawtooth", (0..n as u64).map(|i| i % 64).collect()),
+            ("duplicates", random_vec(n, 4, 81)),
+            ("all_equal", vec![5; n]),
+        ]
+    }
+
+    #[test]
+    fn test_pdq_sort() {
+        let mut arr = vec![10, 7, 8, 9, 1, 5];
+        pdq_sort(&mut arr);
+        assert_eq!(arr, vec![1, 5, 7, 8, 9, 10]);
+
+        for len in [0, 1, 2, 20, 21, 49, 50, 1000, 5000] {
+            let input = random_vec(len, 1000, len as u64 + 82);
+            let mut expected = input.clone();
+            expected.sort();
+
+            let mut arr = input.clone();
+            pdq_sort(&mut arr);
+            assert_eq!(arr, expected, "len = {}", len);
+
+            let mut arr = input;
+            pdq_sort_with_order(&mut arr, Order::Descending);
+            expected.reverse();
+            assert_eq!(arr, expected, "len = {}", len);
+        }
+    }
+
+    #[test]
+    fn test_patterns() {
+        for (name, input) in patterns(10_000) {
+            let mut expected = input.clone();
+            expected.sort();
+            let mut arr = input;
+            pdq_sort(&mut arr);
+            assert_eq!(arr, expected, "{}", name);
+        }
+    }
+
+    #[test]
+    fn test_fewer_comparisons_than_quick_sorts() {
+        let pdq: SortFn = |a, c| pdq_sort_by(a, c);
+        let lomuto: SortFn = |a, c| quick_sort_by(a, c);
+        let hoare: SortFn = |a, c| quick_sort_hoare_by(a, c);
+        let three_way: SortFn = |a, c| quick_sort_3way_by(a, c);
+
+        for (name, input) in patterns(2000) {
+            let ours = count_comparisons(&input, pdq);
+            assert!(ours < count_comparisons(&input, lomuto), "{}", name);
+            assert!(ours < count_comparisons(&input, hoare), "{}", name);
+        }
+
+        // 三路快排一次比较就能区分小于、等于、大于，重复元素多时比较次数更少，
+        // 但有序、逆序输入上 pdqsort 只需要 O(n) 次比较
+        for (name, input) in patterns(2000).into_iter().take(2) {
+            let ours = count_comparisons(&input, pdq);
+            assert!(ours < 2 * input.len(), "{}", name);
+            assert!(ours < count_comparisons(&input, three_way), "{}", name);
+        }
+    }
+
+    #[test]
+    fn test_sawtooth_and_few_distinct_values() {
+        let pdq: SortFn = |a, c| pdq_sort_by(a, c);
+        let lomuto: SortFn = |a, c| quick_sort_by(a, c);
+        let three_way: SortFn = |a, c| quick_sort_3way_by(a, c);
+
+        let n = 5000;
+        let inputs = [
+            ("sawtooth", (0..n as u64).map(|i| i % 64).collect()),
+            ("long_sawtooth", (0..n as u64).map(|i| i % 625).collect()),
+            ("four_values", random_vec(n, 4, 84)),
+            ("hundred_values", random_vec(n, 100, 85)),
+        ];
+        for (name, input) in inputs {
+            let ours = count_comparisons(&input, pdq);
+            assert!(ours < count_comparisons(&input, lomuto), "{}", name);
+            // 模块文档中说明的上界：不超过三路快排的两倍
+            assert!(ours < 2 * count_comparisons(&input, three_way), "{}", name);
+        }
+    }
+
+    #[test]
+    fn test_heap_sort_fallback() {
+        let input = random_vec(500, 1000, 83);
+        let mut expected = input.clone();
+        expected.sort();
+        let mut arr = input;
+        pdq_recursive(&mut arr, &mut u64::cmp, None, 0);
+        assert_eq!(arr, expected);
+    }
+}
@@ -0,0 +1,1355 @@
+//! 快速排序 (Quick Sort)
+//!
+//! # 算法原理
+//! 快速排序使用分治策略：
+//! 1. 选择一个基准元素(pivot)
+//! 2. 将数组分为两部分：小于pivot和大于pivot
+//! 3. 递归排序两部分
+//!
+//! # 时间复杂度
+//! - 最好/平均: O(n log n)
+//! - 最坏: O(n²) - 当每次选择的pivot都是最大或最小值时
+//!
+//! # 空间复杂度: O(log n) - 递归栈空间
+//! # 稳定性: 不稳定
+
+use crate::cached_key::sort_by_cached_key;
+use crate::heap_sort::heap_sort_by;
+use crate::insertion_sort::insertion_sort_by;
+use crate::intro_sort::median_of_three;
+use crate::{Order, Parallelism};
+use std::cmp::Ordering;
+use std::collections::VecDeque;
+use std::sync::atomic::{self, AtomicBool, AtomicUsize};
+use std::sync::{Condvar, Mutex};
+use std::thread;
+
+/// 快速排序主函数
+pub fn quick_sort<T: Ord>(arr: &mut [T]) {
+    quick_sort_by(arr, T::cmp);
+}
+
+/// 按指定方向排序的快速排序
+pub fn quick_sort_with_order<T: Ord>(arr: &mut [T], order: Order) {
+    quick_sort_by(arr, |a, b| order.apply(a.cmp(b)));
+}
+
+/// 使用自定义比较函数的快速排序（Lomuto分区）
+pub fn quick_sort_by<T, F>(arr: &mut [T], mut compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    quick_sort_recursive(arr, &mut compare);
+}
+
+/// 按键排序的快速排序，比较 `key(a)` 与 `key(b)`
+///
+/// 每次比较都会重新计算键，键的计算代价较高时改用 [`quick_sort_by_cached_key`]。
+pub fn quick_sort_by_key<T, K, F>(arr: &mut [T], mut key: F)
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    quick_sort_by(arr, |a, b| key(a).cmp(&key(b)));
+}
+
+/// 缓存键的快速排序：每个元素的键只计算一次，结果是稳定的
+pub fn quick_sort_by_cached_key<T, K, F>(arr: &mut [T], key: F)
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    sort_by_cached_key(arr, key, quick_sort);
+}
+
+fn quick_sort_recursive<T, F>(arr: &mut [T], compare: &mut F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    if arr.len() <= 1 {
+        return;
+    }
+
+    // 分区并获取pivot的最终位置
+    let pivot_index = partition_by(arr, &mut *compare);
+
+    // 递归排序左右两部分
+    quick_sort_recursive(&mut arr[..pivot_index], compare);
+    quick_sort_recursive(&mut arr[pivot_index + 1..], compare);
+}
+
+/// Lomuto分区方案
+/// 选择最后一个元素作为pivot
+pub fn partition<T: Ord>(arr: &mut [T]) -> usize {
+    partition_by(arr, T::cmp)
+}
+
+/// 使用自定义比较函数的Lomuto分区
+pub fn partition_by<T, F>(arr: &mut [T], mut compare: F) -> usize
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let n = arr.len();
+    let pivot_index = n - 1;
+
+    // i 表示小于pivot的区域的右边界
+    let mut i = 0;
+
+    for j in 0..pivot_index {
+        // 如果当前元素小于pivot，将其移到左边区域
+        if compare(&arr[j], &arr[pivot_index]).is_lt() {
+            arr.swap(i, j);
+            i += 1;
+        }
+    }
+
+    // 将pivot放到正确的位置
+    arr.swap(i, pivot_index);
+    i
+}
+
+/// 使用Hoare分区方案的快速排序
+pub fn quick_sort_hoare<T: Ord>(arr: &mut [T]) {
+    quick_sort_hoare_by(arr, T::cmp);
+}
+
+/// 按指定方向排序的Hoare分区快速排序
+pub fn quick_sort_hoare_with_order<T: Ord>(arr: &mut [T], order: Order) {
+    quick_sort_hoare_by(arr, |a, b| order.apply(a.cmp(b)));
+}
+
+/// 使用自定义比较函数的Hoare分区快速排序
+pub fn quick_sort_hoare_by<T, F>(arr: &mut [T], mut compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    quick_sort_hoare_recursive(arr, &mut compare);
+}
+
+/// 按键排序的Hoare分区快速排序，比较 `key(a)` 与 `key(b)`
+pub fn quick_sort_hoare_by_key<T, K, F>(arr: &mut [T], mut key: F)
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    quick_sort_hoare_by(arr, |a, b| key(a).cmp(&key(b)));
+}
+
+fn quick_sort_hoare_recursive<T, F>(arr: &mut [T], compare: &mut F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    if arr.len() <= 1 {
+        return;
+    }
+
+    let pivot_index = partition_hoare_by(arr, &mut *compare);
+
+    quick_sort_hoare_recursive(&mut arr[..pivot_index], compare);
+    quick_sort_hoare_recursive(&mut arr[pivot_index + 1..], compare);
+}
+
+/// Hoare分区方案
+/// 使用双指针从两端向中间扫描
+///
+/// 返回基准的最终位置 `p`：`arr[..p]` 都不大于基准，`arr[p + 1..]` 都不小于基准。
+pub fn partition_hoare<T: Ord>(arr: &mut [T]) -> usize {
+    partition_hoare_by(arr, T::cmp)
+}
+
+/// 使用自定义比较函数的Hoare分区，返回值的含义与 [`partition_hoare`] 相同
+pub fn partition_hoare_by<T, F>(arr: &mut [T], mut compare: F) -> usize
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    // 选择中间元素作为pivot，避免最坏情况
+    // 把它换到开头，扫描时一直借用它，交换不会改变基准
+    let mid = arr.len() / 2;
+    arr.swap(0, mid);
+    let (head, rest) = arr.split_at_mut(1);
+    let pivot = &head[0];
+
+    // rest[..i] 都不大于pivot，rest[j..] 都不小于pivot
+    let mut i = 0;
+    let mut j = rest.len();
+
+    loop {
+        // 从左边找到第一个大于等于pivot的元素
+        while i < j && compare(&rest[i], pivot).is_lt() {
+            i += 1;
+        }
+
+        // 从右边找到第一个小于等于pivot的元素
+        while i < j && compare(&rest[j - 1], pivot).is_gt() {
+            j -= 1;
+        }
+
+        if i >= j {
+            break;
+        }
+
+        // 与pivot相等的元素也会交换，大量重复元素时两侧依然均衡
+        j -= 1;
+        rest.swap(i, j);
+        i += 1;
+    }
+
+    // 将pivot放到左侧区域的末尾，也就是它的最终位置
+    arr.swap(0, i);
+    i
+}
+
+/// 使用分块分区的快速排序（BlockQuicksort），随机数据上几乎没有分支预测失败
+pub fn quick_sort_block<T: Ord>(arr: &mut [T]) {
+    quick_sort_block_by(arr, T::cmp);
+}
+
+/// 按指定方向排序的分块分区快速排序
+pub fn quick_sort_block_with_order<T: Ord>(arr: &mut [T], order: Order) {
+    quick_sort_block_by(arr, |a, b| order.apply(a.cmp(b)));
+}
+
+/// 使用自定义比较函数的分块分区快速排序
+pub fn quick_sort_block_by<T, F>(arr: &mut [T], compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    quick_sort_with_scheme_by(arr, PartitionScheme::Block, compare);
+}
+
+/// 按键排序的分块分区快速排序，比较 `key(a)` 与 `key(b)`
+pub fn quick_sort_block_by_key<T, K, F>(arr: &mut [T], mut key: F)
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    quick_sort_block_by(arr, |a, b| key(a).cmp(&key(b)));
+}
+
+/// 分块分区方案
+/// 以中间元素为基准，与Hoare分区的结果相同，但比较与交换分开进行
+///
+/// 两端各取一块（最多 `BLOCK` 个元素），先只比较、把放错一侧的元素的偏移量记录下来，
+/// 记录时不用分支：偏移量总是写入，计数加上比较结果。之后再成对交换两块中放错的元素。
+/// 比较结果不再决定程序走哪个分支，随机数据上不会频繁地预测失败。
+///
+/// 返回基准的最终位置 `p`：`arr[..p]` 都不大于基准，`arr[p + 1..]` 都不小于基准。
+pub fn partition_block<T: Ord>(arr: &mut [T]) -> usize {
+    partition_block_by(arr, T::cmp)
+}
+
+/// 使用自定义比较函数的分块分区
+pub fn partition_block_by<T, F>(arr: &mut [T], mut compare: F) -> usize
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let mid = arr.len() / 2;
+    arr.swap(0, mid);
+    let (head, rest) = arr.split_at_mut(1);
+    let pivot = &head[0];
+
+    // 与Hoare分区一样，等于基准的元素在两侧都算放错，大量重复元素时两侧依然均衡
+    let i = partition_in_blocks(rest, |x, in_left| {
+        if in_left {
+            !compare(x, pivot).is_lt()
+        } else {
+            !compare(x, pivot).is_gt()
+        }
+    });
+
+    arr.swap(0, i);
+    i
+}
+
+/// 分块分区每块的元素个数，偏移量用 `u8` 记录，不能超过 256
+const BLOCK: usize = 128;
+
+/// 分块分区的核心：把 `arr` 分成左右两部分，返回分界位置
+///
+/// `misplaced(x, in_left)` 表示位于左侧（`in_left` 为 `true`）或右侧的元素 `x` 是否放错了一侧。
+/// 返回 `mid`：`arr[..mid]` 中的每个 `x` 满足 `!misplaced(x, true) || misplaced(x, false)`，
+/// `arr[mid..]` 中的每个 `x` 满足 `!misplaced(x, false) || misplaced(x, true)`。
+///
+/// 两个条件互补时（例如 pdqsort 中左侧不小于基准、右侧小于基准算放错），
+/// `arr[..mid]` 恰好是 `!misplaced(x, true)` 的元素。[`partition_block_by`] 把等于基准的元素
+/// 在两侧都算放错，这些元素可能落在 `mid` 的任一侧，只保证在 `compare` 下
+/// `arr[..mid]` 都不大于基准、`arr[mid..]` 都不小于基准。
+pub(crate) fn partition_in_blocks<T>(
+    arr: &mut [T],
+    mut misplaced: impl FnMut(&T, bool) -> bool,
+) -> usize {
+    // arr[..l] 属于左侧，arr[r..] 属于右侧
+    let mut l = 0;
+    let mut r = arr.len();
+
+    // 左块是 arr[l..l + block_l]，offsets_l[start_l..end_l] 是其中放错、还没交换的元素
+    let mut block_l = BLOCK;
+    let mut offsets_l = [0u8; BLOCK];
+    let (mut start_l, mut end_l) = (0, 0);
+
+    // 右块是 arr[r - block_r..r]，偏移量从右往左数
+    let mut block_r = BLOCK;
+    let mut offsets_r = [0u8; BLOCK];
+    let (mut start_r, mut end_r) = (0, 0);
+
+    loop {
+        // 剩余部分不超过两块时是最后一轮，按剩余长度缩小块，使两块正好覆盖剩余部分
+        let is_done = r - l <= 2 * BLOCK;
+        if is_done {
+            let mut rem = r - l;
+            if start_l < end_l || start_r < end_r {
+                rem -= BLOCK;
+            }
+            if start_l < end_l {
+                block_r = rem;
+            } else if start_r < end_r {
+                block_l = rem;
+            } else {
+                block_l = rem / 2;
+                block_r = rem - block_l;
+            }
+        }
+
+        // 扫描新的一块，只记录放错的元素
+        if start_l == end_l {
+            start_l = 0;
+            end_l = 0;
+            for i in 0..block_l {
+                offsets_l[end_l] = i as u8;
+                end_l += misplaced(&arr[l + i], true) as usize;
+            }
+        }
+        if start_r == end_r {
+            start_r = 0;
+            end_r = 0;
+            for i in 0..block_r {
+                offsets_r[end_r] = i as u8;
+                end_r += misplaced(&arr[r - 1 - i], false) as usize;
+            }
+        }
+
+        // 成对交换两块中放错的元素
+        let count = (end_l - start_l).min(end_r - start_r);
+        for k in 0..count {
+            let left = l + offsets_l[start_l + k] as usize;
+            let right = r - 1 - offsets_r[start_r + k] as usize;
+            arr.swap(left, right);
+        }
+        start_l += count;
+        start_r += count;
+
+        // 放错的元素都处理完的块已经分好
+        if start_l == end_l {
+            l += block_l;
+        }
+        if start_r == end_r {
+            r -= block_r;
+        }
+
+        if is_done {
+            break;
+        }
+    }
+
+    // 最多还剩一块有放错的元素，另一侧已经处理完，块正好与另一侧相接：
+    // 从离分界最近的开始，把它们依次移到块靠近分界的一端
+    if start_l < end_l {
+        while start_l < end_l {
+            end_l -= 1;
+            arr.swap(l + offsets_l[end_l] as usize, r - 1);
+            r -= 1;
+        }
+        r
+    } else {
+        while start_r < end_r {
+            end_r -= 1;
+            arr.swap(l, r - 1 - offsets_r[end_r] as usize);
+            l += 1;
+        }
+        l
+    }
+}
+
+/// 快速排序使用的分区方案
+///
+/// 二路分区的快速排序（包括内省排序和并行快速排序）直接使用对应的分区函数。
+/// 三路快排和双轴快排把数组分成三部分：选择 `Block` 时用两趟分块分区完成，
+/// 见 [`partition_3way_block_by`] 和 [`partition_dual_pivot_block_by`]；
+/// 选择 `Lomuto` 或 `Hoare` 时使用它们原来的单趟扫描（Dijkstra 三路分区、Yaroslavskiy 分区）。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PartitionScheme {
+    /// Lomuto分区：单指针扫描，每次比较后可能交换
+    Lomuto,
+    /// Hoare分区：双指针从两端向中间扫描
+    Hoare,
+    /// 分块分区：先比较、记录放错的元素，再成批交换
+    Block,
+}
+
+impl PartitionScheme {
+    /// 以中间元素为基准分区，返回基准的最终位置 `p`：
+    /// `arr[..p]` 都不大于基准，`arr[p + 1..]` 都不小于基准
+    pub fn partition_by<T, F>(self, arr: &mut [T], compare: F) -> usize
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        match self {
+            PartitionScheme::Lomuto => {
+                // Lomuto分区以最后一个元素为基准，先把中间元素换过去
+                let n = arr.len();
+                arr.swap(n / 2, n - 1);
+                partition_by(arr, compare)
+            }
+            PartitionScheme::Hoare => partition_hoare_by(arr, compare),
+            PartitionScheme::Block => partition_block_by(arr, compare),
+        }
+    }
+}
+
+/// 使用指定分区方案的快速排序
+pub fn quick_sort_with_scheme<T: Ord>(arr: &mut [T], scheme: PartitionScheme) {
+    quick_sort_with_scheme_by(arr, scheme, T::cmp);
+}
+
+/// 使用指定分区方案和自定义比较函数的快速排序
+pub fn quick_sort_with_scheme_by<T, F>(arr: &mut [T], scheme: PartitionScheme, mut compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    quick_sort_scheme_recursive(arr, scheme, &mut compare);
+}
+
+fn quick_sort_scheme_recursive<T, F>(arr: &mut [T], scheme: PartitionScheme, compare: &mut F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    if arr.len() <= 1 {
+        return;
+    }
+
+    let pivot_index = scheme.partition_by(arr, &mut *compare);
+
+    quick_sort_scheme_recursive(&mut arr[..pivot_index], scheme, compare);
+    quick_sort_scheme_recursive(&mut arr[pivot_index + 1..], scheme, compare);
+}
+
+/// 三路快排 - 适合处理大量重复元素
+pub fn quick_sort_3way<T: Ord>(arr: &mut [T]) {
+    quick_sort_3way_by(arr, T::cmp);
+}
+
+/// 按指定方向排序的三路快排
+pub fn quick_sort_3way_with_order<T: Ord>(arr: &mut [T], order: Order) {
+    quick_sort_3way_by(arr, |a, b| order.apply(a.cmp(b)));
+}
+
+/// 使用自定义比较函数的三路快排
+pub fn quick_sort_3way_by<T, F>(arr: &mut [T], compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    quick_sort_3way_with_scheme_by(arr, PartitionScheme::Lomuto, compare);
+}
+
+/// 按键排序的三路快排，比较 `key(a)` 与 `key(b)`
+pub fn quick_sort_3way_by_key<T, K, F>(arr: &mut [T], mut key: F)
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    quick_sort_3way_by(arr, |a, b| key(a).cmp(&key(b)));
+}
+
+/// 使用指定分区方案的三路快排，`Block` 时使用 [`partition_3way_block_by`]
+pub fn quick_sort_3way_with_scheme<T: Ord>(arr: &mut [T], scheme: PartitionScheme) {
+    quick_sort_3way_with_scheme_by(arr, scheme, T::cmp);
+}
+
+/// 使用指定分区方案和自定义比较函数的三路快排
+pub fn quick_sort_3way_with_scheme_by<T, F>(arr: &mut [T], scheme: PartitionScheme, mut compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    quick_sort_3way_recursive(arr, scheme, &mut compare);
+}
+
+fn quick_sort_3way_recursive<T, F>(arr: &mut [T], scheme: PartitionScheme, compare: &mut F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    if arr.len() <= 1 {
+        return;
+    }
+
+    let (lt, gt) = match scheme {
+        PartitionScheme::Block => partition_3way_block_by(arr, &mut *compare),
+        PartitionScheme::Lomuto | PartitionScheme::Hoare => partition_3way_by(arr, &mut *compare),
+    };
+
+    if lt > 0 {
+        quick_sort_3way_recursive(&mut arr[..lt], scheme, compare);
+    }
+    if gt < arr.len() - 1 {
+        quick_sort_3way_recursive(&mut arr[gt + 1..], scheme, compare);
+    }
+}
+
+/// 三路分区：将数组分成 <pivot, =pivot, >pivot 三部分
+pub fn partition_3way<T: Ord>(arr: &mut [T]) -> (usize, usize) {
+    partition_3way_by(arr, T::cmp)
+}
+
+/// 使用自定义比较函数的三路分区
+pub fn partition_3way_by<T, F>(arr: &mut [T], mut compare: F) -> (usize, usize)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    // 把中间元素换到开头作为基准，分区时只借用它，不需要复制
+    arr.swap(0, arr.len() / 2);
+    let (head, rest) = arr.split_at_mut(1);
+    let pivot = &head[0];
+
+    let mut lt = 0; // rest[0..lt] < pivot
+    let mut i = 0; // rest[lt..i] == pivot
+    let mut gt = rest.len(); // rest[gt..] > pivot
+
+    while i < gt {
+        match compare(&rest[i], pivot) {
+            Ordering::Less => {
+                rest.swap(lt, i);
+                lt += 1;
+                i += 1;
+            }
+            Ordering::Greater => {
+                gt -= 1;
+                rest.swap(i, gt);
+            }
+            Ordering::Equal => {
+                i += 1;
+            }
+        }
+    }
+
+    // 基准与最后一个小于它的元素交换，arr[lt..=gt] 都等于基准
+    arr.swap(0, lt);
+    (lt, gt)
+}
+
+/// 使用分块分区的三路分区，返回值与 [`partition_3way`] 相同
+pub fn partition_3way_block<T: Ord>(arr: &mut [T]) -> (usize, usize) {
+    partition_3way_block_by(arr, T::cmp)
+}
+
+/// 使用分块分区和自定义比较函数的三路分区
+///
+/// 以中间元素为基准做两趟分块分区：先分出小于基准的元素，再把其余元素中
+/// 等于基准的放到大于基准的前面。比较次数比单趟扫描多，但两趟都不依赖比较结果分支。
+pub fn partition_3way_block_by<T, F>(arr: &mut [T], mut compare: F) -> (usize, usize)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    arr.swap(0, arr.len() / 2);
+    let (head, rest) = arr.split_at_mut(1);
+    let pivot = &head[0];
+
+    // rest[..lt] 小于基准，rest[lt..lt + eq] 等于基准
+    let lt = partition_in_blocks(rest, |x, in_left| compare(x, pivot).is_lt() != in_left);
+    let eq = partition_in_blocks(&mut rest[lt..], |x, in_left| {
+        compare(x, pivot).is_gt() == in_left
+    });
+
+    arr.swap(0, lt);
+    (lt, lt + eq)
+}
+
+/// 双轴快速排序的插入排序阈值
+pub const DUAL_PIVOT_INSERTION_THRESHOLD: usize = 27;
+
+/// 双轴快排（Yaroslavskiy 分区，Java 对基本类型数组排序使用的方案）
+pub fn quick_sort_dual_pivot<T: Ord>(arr: &mut [T]) {
+    quick_sort_dual_pivot_by(arr, T::cmp);
+}
+
+/// 按指定方向排序的双轴快排
+pub fn quick_sort_dual_pivot_with_order<T: Ord>(arr: &mut [T], order: Order) {
+    quick_sort_dual_pivot_by(arr, |a, b| order.apply(a.cmp(b)));
+}
+
+/// 使用自定义比较函数的双轴快排
+pub fn quick_sort_dual_pivot_by<T, F>(arr: &mut [T], compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    quick_sort_dual_pivot_with_scheme_by(arr, PartitionScheme::Lomuto, compare);
+}
+
+/// 按键排序的双轴快排，比较 `key(a)` 与 `key(b)`
+pub fn quick_sort_dual_pivot_by_key<T, K, F>(arr: &mut [T], mut key: F)
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    quick_sort_dual_pivot_by(arr, |a, b| key(a).cmp(&key(b)));
+}
+
+/// 使用指定分区方案的双轴快排，`Block` 时使用 [`partition_dual_pivot_block_by`]
+pub fn quick_sort_dual_pivot_with_scheme<T: Ord>(arr: &mut [T], scheme: PartitionScheme) {
+    quick_sort_dual_pivot_with_scheme_by(arr, scheme, T::cmp);
+}
+
+/// 使用指定分区方案和自定义比较函数的双轴快排
+pub fn quick_sort_dual_pivot_with_scheme_by<T, F>(
+    arr: &mut [T],
+    scheme: PartitionScheme,
+    mut compare: F,
+) where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    quick_sort_dual_pivot_recursive(arr, scheme, &mut compare);
+}
+
+fn quick_sort_dual_pivot_recursive<T, F>(arr: &mut [T], scheme: PartitionScheme, compare: &mut F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let n = arr.len();
+    if n < DUAL_PIVOT_INSERTION_THRESHOLD {
+        insertion_sort_by(arr, &mut *compare);
+        return;
+    }
+
+    let (lt, gt) = match scheme {
+        PartitionScheme::Block => partition_dual_pivot_block_by(arr, &mut *compare),
+        PartitionScheme::Lomuto | PartitionScheme::Hoare => {
+            partition_dual_pivot_by(arr, &mut *compare)
+        }
+    };
+
+    let (left, rest) = arr.split_at_mut(lt);
+    let (pivot1, rest) = rest.split_first_mut().unwrap();
+    let (middle, rest) = rest.split_at_mut(gt - lt - 1);
+    let (pivot2, right) = rest.split_first_mut().unwrap();
+
+    quick_sort_dual_pivot_recursive(left, scheme, compare);
+    quick_sort_dual_pivot_recursive(right, scheme, compare);
+
+    // 两个基准相等时，中间部分的元素都等于基准
+    if compare(pivot1, pivot2).is_eq() {
+        return;
+    }
+
+    // 中间部分覆盖了最外侧的两个样本时，其中多半有大量等于基准的元素，
+    // 先把它们移到两端，否则重复元素多的输入会退化为 O(n²)
+    let mut middle = middle;
+    if lt < n / 6 && gt > n * 5 / 6 {
+        let mut lo = 0; // middle[..lo] == pivot1
+        let mut hi = middle.len(); // middle[hi..] == pivot2
+        let mut k = 0;
+        while k < hi {
+            if compare(&middle[k], pivot1).is_eq() {
+                middle.swap(k, lo);
+                lo += 1;
+                k += 1;
+            } else if compare(&middle[k], pivot2).is_eq() {
+                hi -= 1;
+                middle.swap(k, hi);
+            } else {
+                k += 1;
+            }
+        }
+        middle = &mut middle[lo..hi];
+    }
+    quick_sort_dual_pivot_recursive(middle, scheme, compare);
+}
+
+/// 双轴分区：将数组分成 <pivot1, pivot1..=pivot2, >pivot2 三部分
+pub fn partition_dual_pivot<T: Ord>(arr: &mut [T]) -> (usize, usize) {
+    partition_dual_pivot_by(arr, T::cmp)
+}
+
+/// 使用自定义比较函数的双轴分区
+///
+/// 在 1/6, 2/6, ..., 5/6 处取五个样本排好序，第二个和第四个作为两个基准
+/// （少于五个元素时取首尾两个）。返回两个基准的最终位置 `(lt, gt)`：
+/// `arr[..lt]` 小于 pivot1，`arr[lt + 1..gt]` 在两个基准之间，`arr[gt + 1..]` 大于 pivot2。
+///
+/// # Panics
+/// 数组少于两个元素时 panic。
+pub fn partition_dual_pivot_by<T, F>(arr: &mut [T], mut compare: F) -> (usize, usize)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let n = arr.len();
+    assert!(n >= 2, "双轴分区至少需要两个元素");
+    select_dual_pivots(arr, &mut compare);
+
+    // 分区时只借用两个基准
+    let (head, rest) = arr.split_at_mut(1);
+    let (rest, tail) = rest.split_at_mut(n - 2);
+    let (pivot1, pivot2) = (&head[0], &tail[0]);
+
+    let mut lt = 0; // rest[..lt] < pivot1
+    let mut k = 0; // rest[lt..k] 在两个基准之间
+    let mut gt = rest.len(); // rest[gt..] > pivot2
+
+    while k < gt {
+        if compare(&rest[k], pivot1).is_lt() {
+            rest.swap(k, lt);
+            lt += 1;
+        } else if compare(&rest[k], pivot2).is_gt() {
+            // 从右侧找一个不大于 pivot2 的元素交换过来
+            gt -= 1;
+            while k < gt && compare(&rest[gt], pivot2).is_gt() {
+                gt -= 1;
+            }
+            rest.swap(k, gt);
+            if compare(&rest[k], pivot1).is_lt() {
+                rest.swap(k, lt);
+                lt += 1;
+            }
+        }
+        k += 1;
+    }
+
+    // 两个基准分别与相邻区域的边界元素交换，放到最终位置
+    arr.swap(0, lt);
+    arr.swap(n - 1, gt + 1);
+    (lt, gt + 1)
+}
+
+/// 使用分块分区的双轴分区，返回值与 [`partition_dual_pivot_by`] 相同
+pub fn partition_dual_pivot_block<T: Ord>(arr: &mut [T]) -> (usize, usize) {
+    partition_dual_pivot_block_by(arr, T::cmp)
+}
+
+/// 使用分块分区和自定义比较函数的双轴分区
+///
+/// 基准的选择与 [`partition_dual_pivot_by`] 相同，之后做两趟分块分区：
+/// 先分出小于 pivot1 的元素，再把其余元素中不大于 pivot2 的放到大于 pivot2 的前面。
+///
+/// # Panics
+/// 数组少于两个元素时 panic。
+pub fn partition_dual_pivot_block_by<T, F>(arr: &mut [T], mut compare: F) -> (usize, usize)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let n = arr.len();
+    assert!(n >= 2, "双轴分区至少需要两个元素");
+    select_dual_pivots(arr, &mut compare);
+
+    let (head, rest) = arr.split_at_mut(1);
+    let (rest, tail) = rest.split_at_mut(n - 2);
+    let (pivot1, pivot2) = (&head[0], &tail[0]);
+
+    // rest[..lt] < pivot1，rest[lt..gt] 在两个基准之间，rest[gt..] > pivot2
+    let lt = partition_in_blocks(rest, |x, in_left| compare(x, pivot1).is_lt() != in_left);
+    let gt = lt
+        + partition_in_blocks(&mut rest[lt..], |x, in_left| {
+            compare(x, pivot2).is_gt() == in_left
+        });
+
+    arr.swap(0, lt);
+    arr.swap(n - 1, gt + 1);
+    (lt, gt + 1)
+}
+
+/// 在 1/6, 2/6, ..., 5/6 处取五个样本排好序，第二个和第四个分别换到开头和末尾作为两个基准
+/// （少于五个元素时取首尾两个）
+fn select_dual_pivots<T, F>(arr: &mut [T], compare: &mut F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let n = arr.len();
+    if n >= 5 {
+        let samples: [usize; 5] = std::array::from_fn(|i| (i + 1) * n / 6);
+        for i in 1..samples.len() {
+            let mut j = i;
+            while j > 0 && compare(&arr[samples[j]], &arr[samples[j - 1]]).is_lt() {
+                arr.swap(samples[j], samples[j - 1]);
+                j -= 1;
+            }
+        }
+        arr.swap(0, samples[1]);
+        arr.swap(n - 1, samples[3]);
+    } else if compare(&arr[n - 1], &arr[0]).is_lt() {
+        arr.swap(0, n - 1);
+    }
+}
+
+/// 并行快速排序，线程数取 [`Parallelism::default`]
+pub fn quick_sort_parallel<T: Ord + Send>(arr: &mut [T]) {
+    quick_sort_parallel_by(arr, T::cmp);
+}
+
+/// 按指定方向排序的并行快速排序
+pub fn quick_sort_parallel_with_order<T: Ord + Send>(arr: &mut [T], order: Order) {
+    quick_sort_parallel_by(arr, |a, b| order.apply(a.cmp(b)));
+}
+
+/// 使用自定义比较函数的并行快速排序
+///
+/// `compare` 会在多个线程中同时调用，所以要求 `Fn + Sync` 而不是 `FnMut`。
+pub fn quick_sort_parallel_by<T, F>(arr: &mut [T], compare: F)
+where
+    T: Send,
+    F: Fn(&T, &T) -> Ordering + Sync,
+{
+    quick_sort_with_parallelism_by(arr, Parallelism::default(), compare);
+}
+
+/// 按键排序的并行快速排序，比较 `key(a)` 与 `key(b)`
+pub fn quick_sort_parallel_by_key<T, K, F>(arr: &mut [T], key: F)
+where
+    T: Send,
+    K: Ord,
+    F: Fn(&T) -> K + Sync,
+{
+    quick_sort_parallel_by(arr, |a, b| key(a).cmp(&key(b)));
+}
+
+/// 指定线程数和顺序排序阈值的并行快速排序
+///
+/// # 示例
+/// ```
+/// use sort::quick_sort::quick_sort_with_parallelism;
+/// use sort::Parallelism;
+///
+/// let mut arr: Vec<u64> = (0..100_000).map(|i| (i * 7919) % 100_003).collect();
+/// quick_sort_with_parallelism(&mut arr, Parallelism::new(4).with_sequential_cutoff(4096));
+/// assert!(arr.windows(2).all(|w| w[0] <= w[1]));
+/// ```
+pub fn quick_sort_with_parallelism<T: Ord + Send>(arr: &mut [T], parallelism: Parallelism) {
+    quick_sort_with_parallelism_by(arr, parallelism, T::cmp);
+}
+
+/// 指定线程数、顺序排序阈值和比较函数的并行快速排序，使用分块分区
+pub fn quick_sort_with_parallelism_by<T, F>(arr: &mut [T], parallelism: Parallelism, compare: F)
+where
+    T: Send,
+    F: Fn(&T, &T) -> Ordering + Sync,
+{
+    quick_sort_with_parallelism_and_scheme_by(arr, parallelism, PartitionScheme::Block, compare);
+}
+
+/// 指定线程数、顺序排序阈值和分区方案的并行快速排序
+pub fn quick_sort_with_parallelism_and_scheme<T: Ord + Send>(
+    arr: &mut [T],
+    parallelism: Parallelism,
+    scheme: PartitionScheme,
+) {
+    quick_sort_with_parallelism_and_scheme_by(arr, parallelism, scheme, T::cmp);
+}
+
+/// 指定线程数、顺序排序阈值、分区方案和比较函数的并行快速排序
+///
+/// 调用方线程先对整个数组做三数取中和 `scheme` 指定的二路分区，之后每次分区都把
+/// 较大的一侧交给工作窃取线程池、自己继续处理较小的一侧。不超过 `sequential_cutoff`
+/// 的子数组直接用同一分区方案的三路快排（很短时用插入排序）在当前线程排好；
+/// 分区的层数超过 2·log₂(n) 说明基准持续失衡，和内省排序一样改用堆排序，保证 O(n log n)。
+pub fn quick_sort_with_parallelism_and_scheme_by<T, F>(
+    arr: &mut [T],
+    parallelism: Parallelism,
+    scheme: PartitionScheme,
+    compare: F,
+) where
+    T: Send,
+    F: Fn(&T, &T) -> Ordering + Sync,
+{
+    let threads = parallelism.threads.max(1);
+    let cutoff = parallelism
+        .sequential_cutoff
+        .max(PARALLEL_INSERTION_THRESHOLD);
+    let depth = 2 * arr.len().checked_ilog2().unwrap_or(0);
+    if arr.len() <= cutoff {
+        sort_sequential(arr, scheme, &compare);
+        return;
+    }
+
+    // 只有一个线程时不创建新线程，调用方线程独自处理所有任务
+    let pool = WorkPool {
+        queues: (0..threads).map(|_| Mutex::new(VecDeque::new())).collect(),
+        pending: AtomicUsize::new(1),
+        poisoned: AtomicBool::new(false),
+        idle: Mutex::new(()),
+        wake: Condvar::new(),
+    };
+    pool.queues[0].lock().unwrap().push_back(Job { arr, depth });
+
+    thread::scope(|s| {
+        for worker in 1..threads {
+            let (pool, compare) = (&pool, &compare);
+            s.spawn(move || pool.work(worker, cutoff, scheme, compare));
+        }
+        pool.work(0, cutoff, scheme, &compare);
+    });
+}
+
+/// 并行快速排序中直接用插入排序的阈值
+const PARALLEL_INSERTION_THRESHOLD: usize = 16;
+
+/// 不再拆分的子数组：很短时插入排序，否则三路快排
+fn sort_sequential<T, F>(arr: &mut [T], scheme: PartitionScheme, compare: &F)
+where
+    F: Fn(&T, &T) -> Ordering,
+{
+    if arr.len() <= PARALLEL_INSERTION_THRESHOLD {
+        insertion_sort_by(arr, compare);
+    } else {
+        quick_sort_3way_with_scheme_by(arr, scheme, compare);
+    }
+}
+
+/// 线程池中等待排序的子数组，`depth` 是还允许的分区层数
+struct Job<'a, T> {
+    arr: &'a mut [T],
+    depth: u32,
+}
+
+/// 并行快速排序的工作窃取线程池
+///
+/// 每个线程有自己的双端队列：分出的任务压入自己队列的尾部，也从尾部取回
+/// （后进先出，刚分区过的数据还在缓存中）；自己的队列空了就从其他线程队列的头部窃取，
+/// 那里是较早分出、通常也较大的子数组。所有队列都空时线程在 `wake` 上休眠，
+/// 直到有新任务提交、全部任务完成或线程池中毒。
+struct WorkPool<'a, T> {
+    queues: Vec<Mutex<VecDeque<Job<'a, T>>>>,
+    /// 已经提交但还没有排好的任务数，降为 0 时所有线程退出
+    pending: AtomicUsize,
+    /// 某个线程中 `compare` panic 后置位，其余线程不再取任务，尽快退出
+    poisoned: AtomicBool,
+    /// 休眠前在持有该锁时重新检查队列和状态，唤醒方也先获取该锁，不会丢失通知
+    idle: Mutex<()>,
+    wake: Condvar,
+}
+
+impl<T> WorkPool<'_, T> {
+    /// 全部任务完成或线程池中毒时唤醒所有休眠的线程
+    fn wake_all(&self) {
+        drop(self.idle.lock().unwrap());
+        self.wake.notify_all();
+    }
+}
+
+impl<'a, T: Send> WorkPool<'a, T> {
+    /// 第 `worker` 个线程的主循环
+    fn work<F>(&self, worker: usize, cutoff: usize, scheme: PartitionScheme, compare: &F)
+    where
+        F: Fn(&T, &T) -> Ordering,
+    {
+        let _guard = PoisonOnPanic(self);
+        while !self.poisoned.load(atomic::Ordering::Acquire) {
+            if let Some(job) = self.pop(worker).or_else(|| self.steal(worker)) {
+                self.run(worker, job, cutoff, scheme, compare);
+                if self.pending.fetch_sub(1, atomic::Ordering::AcqRel) == 1 {
+                    self.wake_all();
+                }
+                continue;
+            }
+
+            let idle = self.idle.lock().unwrap();
+            if self.pending.load(atomic::Ordering::Acquire) == 0 {
+                break;
+            }
+            // 其他线程可能在检查之后、加锁之前提交了任务
+            if !self.poisoned.load(atomic::Ordering::Acquire) && !self.has_jobs() {
+                drop(self.wake.wait(idle).unwrap());
+            }
+        }
+    }
+
+    /// 提交任务并唤醒一个休眠的线程
+    fn push(&self, worker: usize, job: Job<'a, T>) {
+        self.pending.fetch_add(1, atomic::Ordering::AcqRel);
+        self.queues[worker].lock().unwrap().push_back(job);
+        drop(self.idle.lock().unwrap());
+        self.wake.notify_one();
+    }
+
+    fn has_jobs(&self) -> bool {
+        self.queues.iter().any(|q| !q.lock().unwrap().is_empty())
+    }
+
+    fn pop(&self, worker: usize) -> Option<Job<'a, T>> {
+        self.queues[worker].lock().unwrap().pop_back()
+    }
+
+    fn steal(&self, worker: usize) -> Option<Job<'a, T>> {
+        let n = self.queues.len();
+        (1..n).find_map(|i| self.queues[(worker + i) % n].lock().unwrap().pop_front())
+    }
+
+    /// 排序一个子数组：较大的一侧提交给线程池，较小的一侧继续在当前线程分区
+    fn run<F>(
+        &self,
+        worker: usize,
+        job: Job<'a, T>,
+        cutoff: usize,
+        scheme: PartitionScheme,
+        mut compare: &F,
+    ) where
+        F: Fn(&T, &T) -> Ordering,
+    {
+        let Job { mut arr, mut depth } = job;
+        loop {
+            if arr.len() <= cutoff {
+                sort_sequential(arr, scheme, compare);
+                return;
+            }
+            if depth == 0 {
+                heap_sort_by(arr, compare);
+                return;
+            }
+            depth -= 1;
+
+            median_of_three(arr, &mut compare);
+            let pivot = scheme.partition_by(arr, compare);
+            let (left, right) = arr.split_at_mut(pivot);
+            let right = &mut right[1..];
+            let (small, large) = if left.len() < right.len() {
+                (left, right)
+            } else {
+                (right, left)
+            };
+
+            self.push(worker, Job { arr: large, depth });
+            arr = small;
+        }
+    }
+}
+
+/// 线程因 panic 退出时通知线程池中的其他线程
+struct PoisonOnPanic<'p, 'a, T>(&'p WorkPool<'a, T>);
+
+impl<T> Drop for PoisonOnPanic<'_, '_, T> {
+    fn drop(&mut self) {
+        if thread::panicking() {
+            self.0.poisoned.store(true, atomic::Ordering::Release);
+            self.0.wake_all();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{assert_each_once, random_vec, tracked_vec};
+
+    #[test]
+    fn test_quick_sort() {
+        let mut arr = vec![10, 7, 8, 9, 1, 5];
+        quick_sort(&mut arr);
+        assert_eq!(arr, vec![1, 5, 7, 8, 9, 10]);
+    }
+
+    #[test]
+    fn test_quick_sort_hoare() {
+        let mut arr = vec![10, 7, 8, 9, 1, 5];
+        quick_sort_hoare(&mut arr);
+        assert_eq!(arr, vec![1, 5, 7, 8, 9, 10]);
+    }
+
+    #[test]
+    fn test_quick_sort_3way() {
+        let mut arr = vec![4, 2, 4, 1, 4, 3];
+        quick_sort_3way(&mut arr);
+        assert_eq!(arr, vec![1, 2, 3, 4, 4, 4]);
+    }
+
+    #[test]
+    fn test_empty() {
+        let mut arr: Vec<i32> = vec![];
+        quick_sort(&mut arr);
+        assert_eq!(arr, vec![]);
+    }
+
+    #[test]
+    fn test_single() {
+        let mut arr = vec![42];
+        quick_sort(&mut arr);
+        assert_eq!(arr, vec![42]);
+    }
+
+    #[test]
+    fn test_quick_sort_by() {
+        let expected = vec![10, 9, 8, 7, 5, 1];
+
+        let mut arr = vec![10, 7, 8, 9, 1, 5];
+        quick_sort_by(&mut arr, |a, b| b.cmp(a));
+        assert_eq!(arr, expected);
+
+        let mut arr = vec![10, 7, 8, 9, 1, 5];
+        quick_sort_hoare_by(&mut arr, |a, b| b.cmp(a));
+        assert_eq!(arr, expected);
+    }
+
+    #[test]
+    fn test_quick_sort_3way_by() {
+        // 按除以 10 的商排序，商相同的元素视为相等
+        let mut arr = vec![42, 17, 45, 3, 11, 40, 8];
+        quick_sort_3way_by(&mut arr, |a, b| (a / 10).cmp(&(b / 10)));
+        let keys: Vec<i32> = arr.iter().map(|x| x / 10).collect();
+        assert_eq!(keys, vec![0, 0, 1, 1, 4, 4, 4]);
+    }
+
+    #[test]
+    fn test_quick_sort_by_key() {
+        let mut arr = vec![-10, 7, -8, 9, 1, -5];
+        quick_sort_by_key(&mut arr, |x: &i32| x.abs());
+        assert_eq!(arr, vec![1, -5, 7, -8, 9, -10]);
+    }
+
+    #[test]
+    fn test_quick_sort_by_cached_key_is_stable() {
+        let mut words = vec!["b", "A", "a", "B", "c"];
+        quick_sort_by_cached_key(&mut words, |w| w.to_lowercase());
+        assert_eq!(words, vec!["A", "a", "b", "B", "c"]);
+    }
+
+    #[test]
+    fn test_3way_moves_without_clone() {
+        let (mut arr, drops) = tracked_vec(&random_vec(300, 5, 41));
+        quick_sort_3way(&mut arr);
+        assert!(arr.windows(2).all(|w| w[0].key <= w[1].key));
+        assert_each_once(arr, &drops);
+    }
+
+    #[test]
+    fn test_quick_sort_dual_pivot() {
+        let mut arr = vec![10, 7, 8, 9, 1, 5];
+        quick_sort_dual_pivot(&mut arr);
+        assert_eq!(arr, vec![1, 5, 7, 8, 9, 10]);
+
+        // 覆盖插入排序阈值附近的长度，以及大量重复、有序、逆序的输入
+        for (len, bound) in [
+            (26, 100),
+            (27, 100),
+            (1000, 1_000_000),
+            (1000, 3),
+            (20_000, 2),
+        ] {
+            let input = random_vec(len, bound, len as u64 + bound);
+            let mut expected = input.clone();
+            expected.sort();
+
+            let mut arr = input;
+            quick_sort_dual_pivot(&mut arr);
+            assert_eq!(arr, expected);
+
+            quick_sort_dual_pivot(&mut arr);
+            assert_eq!(arr, expected);
+
+            arr.reverse();
+            quick_sort_dual_pivot_with_order(&mut arr, Order::Descending);
+            expected.reverse();
+            assert_eq!(arr, expected);
+        }
+    }
+
+    #[test]
+    fn test_partition_dual_pivot() {
+        for len in [2, 3, 4, 5, 6, 50, 333] {
+            let mut arr = random_vec(len, 20, len as u64 + 42);
+            let (lt, gt) = partition_dual_pivot(&mut arr);
+            assert!(lt < gt && gt < len, "len = {}", len);
+            assert!(arr[lt] <= arr[gt]);
+            assert!(arr[..lt].iter().all(|x| *x < arr[lt]));
+            assert!(arr[lt + 1..gt]
+                .iter()
+                .all(|x| arr[lt] <= *x && *x <= arr[gt]));
+            assert!(arr[gt + 1..].iter().all(|x| *x > arr[gt]));
+        }
+    }
+
+    #[test]
+    fn test_partition_3way_block() {
+        for len in [1, 2, 3, 100, 257, 1000] {
+            for bound in [3, 1_000_000] {
+                let mut arr = random_vec(len, bound, len as u64 + bound + 11);
+                let (lt, gt) = partition_3way_block(&mut arr);
+                let pivot = arr[lt];
+                assert!(lt <= gt && gt < len, "len = {}", len);
+                assert!(arr[..lt].iter().all(|x| *x < pivot));
+                assert!(arr[lt..=gt].iter().all(|x| *x == pivot));
+                assert!(arr[gt + 1..].iter().all(|x| *x > pivot));
+            }
+        }
+    }
+
+    #[test]
+    fn test_partition_dual_pivot_block() {
+        for len in [2, 3, 4, 5, 6, 50, 333, 1000] {
+            let mut arr = random_vec(len, 20, len as u64 + 42);
+            let (lt, gt) = partition_dual_pivot_block(&mut arr);
+            assert!(lt < gt && gt < len, "len = {}", len);
+            assert!(arr[lt] <= arr[gt]);
+            assert!(arr[..lt].iter().all(|x| *x < arr[lt]));
+            assert!(arr[lt + 1..gt]
+                .iter()
+                .all(|x| arr[lt] <= *x && *x <= arr[gt]));
+            assert!(arr[gt + 1..].iter().all(|x| *x > arr[gt]));
+        }
+    }
+
+    #[test]
+    fn test_dual_pivot_moves_without_clone() {
+        let (mut arr, drops) = tracked_vec(&random_vec(300, 5, 43));
+        quick_sort_dual_pivot(&mut arr);
+        assert!(arr.windows(2).all(|w| w[0].key <= w[1].key));
+        assert_each_once(arr, &drops);
+    }
+
+    #[test]
+    fn test_partition_in_blocks() {
+        for len in [0, 1, 100, 257, 1000] {
+            let mut arr = random_vec(len, 5, len as u64 + 9);
+            // 等于 2 的元素在两侧都算放错
+            let mid = partition_in_blocks(
+                &mut arr,
+                |x, in_left| if in_left { *x >= 2 } else { *x <= 2 },
+            );
+            assert!(arr[..mid].iter().all(|x| *x <= 2), "len = {}", len);
+            assert!(arr[mid..].iter().all(|x| *x >= 2), "len = {}", len);
+        }
+    }
+
+    #[test]
+    fn test_partition_hoare() {
+        // 返回基准的最终位置，基准是分区前的中间元素
+        for len in [1, 2, 3, 10, 100, 1000] {
+            for bound in [3, 1_000_000] {
+                let mut arr = random_vec(len, bound, len as u64 + bound + 7);
+                let pivot = arr[len / 2];
+                let p = partition_hoare(&mut arr);
+                assert_eq!(arr[p], pivot, "len = {}", len);
+                assert!(arr[..p].iter().all(|x| *x <= pivot), "len = {}", len);
+                assert!(arr[p + 1..].iter().all(|x| *x >= pivot), "len = {}", len);
+            }
+        }
+    }
+
+    #[test]
+    fn test_partition_block() {
+        // 覆盖不足一块、正好两块、多块和最后一轮剩余一块的情况
+        for len in [1, 2, 3, 100, 256, 257, 258, 1000, 5000] {
+            for bound in [3, 1_000_000] {
+                let mut arr = random_vec(len, bound, len as u64 + bound);
+                let p = partition_block(&mut arr);
+                assert!(arr[..p].iter().all(|x| *x <= arr[p]), "len = {}", len);
+                assert!(arr[p + 1..].iter().all(|x| *x >= arr[p]), "len = {}", len);
+            }
+        }
+
+        // 全部相等时基准落在中间附近，而不是一端
+        let mut arr = vec![7; 1000];
+        let p = partition_block(&mut arr);
+        assert!((400..600).contains(&p), "p = {}", p);
+    }
+
+    #[test]
+    fn test_quick_sort_with_scheme() {
+        let schemes = [
+            PartitionScheme::Lomuto,
+            PartitionScheme::Hoare,
+            PartitionScheme::Block,
+        ];
+        for scheme in schemes {
+            for bound in [3, 1_000_000] {
+                let input = random_vec(3000, bound, bound + 44);
+                let mut expected = input.clone();
+                expected.sort();
+
+                let mut arr = input.clone();
+                quick_sort_with_scheme(&mut arr, scheme);
+                assert_eq!(arr, expected, "{:?}", scheme);
+
+                let mut arr = input.clone();
+                quick_sort_3way_with_scheme(&mut arr, scheme);
+                assert_eq!(arr, expected, "3way {:?}", scheme);
+
+                let mut arr = input.clone();
+                quick_sort_dual_pivot_with_scheme(&mut arr, scheme);
+                assert_eq!(arr, expected, "dual pivot {:?}", scheme);
+
+                let parallelism = Parallelism::new(3).with_sequential_cutoff(64);
+                let mut arr = input;
+                quick_sort_with_parallelism_and_scheme(&mut arr, parallelism, scheme);
+                assert_eq!(arr, expected, "parallel {:?}", scheme);
+            }
+        }
+
+        let mut arr = vec![10, 7, 8, 9, 1, 5];
+        quick_sort_block_with_order(&mut arr, Order::Descending);
+        assert_eq!(arr, vec![10, 9, 8, 7, 5, 1]);
+    }
+
+    #[test]
+    fn test_block_moves_without_clone() {
+        let (mut arr, drops) = tracked_vec(&random_vec(1000, 5, 45));
+        quick_sort_block(&mut arr);
+        assert!(arr.windows(2).all(|w| w[0].key <= w[1].key));
+        assert_each_once(arr, &drops);
+    }
+
+    #[test]
+    fn test_quick_sort_parallel() {
+        for threads in [1, 2, 3, 8] {
+            let parallelism = Parallelism::new(threads).with_sequential_cutoff(32);
+            for (len, bound) in [
+                (0, 1),
+                (1, 1),
+                (100, 5),
+                (5000, 1),
+                (5000, 3),
+                (20_000, 1 << 40),
+            ] {
+                let input = random_vec(len, bound, len as u64 + threads as u64);
+                let mut expected = input.clone();
+                expected.sort();
+
+                let mut arr = input;
+                quick_sort_with_parallelism(&mut arr, parallelism);
+                assert_eq!(arr, expected, "threads = {}, len = {}", threads, len);
+
+                // 已经有序和逆序的输入
+                quick_sort_with_parallelism(&mut arr, parallelism);
+                assert_eq!(arr, expected);
+                arr.reverse();
+                quick_sort_with_parallelism_by(&mut arr, parallelism, |a, b| b.cmp(a));
+                expected.reverse();
+                assert_eq!(arr, expected);
+            }
+        }
+
+        let input = random_vec(200_000, 1_000_000, 71);
+        let mut expected = input.clone();
+        expected.sort();
+        let mut arr = input;
+        quick_sort_parallel(&mut arr);
+        assert_eq!(arr, expected);
+    }
+
+    #[test]
+    fn test_parallel_panic_keeps_every_element() {
+        // `Tracked` 不能跨线程，这里用 `Box` 检查元素既没有丢失也没有重复；
+        // 一个线程 panic 后其他线程也要退出，不能一直等待
+        let keys = random_vec(20_000, 1000, 72);
+        let parallelism = Parallelism::new(4).with_sequential_cutoff(100);
+        for limit in [1, 1000, 50_000, 200_000] {
+            let mut arr: Vec<Box<u64>> = keys.iter().copied().map(Box::new).collect();
+            let calls = AtomicUsize::new(0);
+            let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                quick_sort_with_parallelism_by(&mut arr, parallelism, |a, b| {
+                    let calls = calls.fetch_add(1, atomic::Ordering::Relaxed);
+                    assert!(calls < limit, "比较函数 panic");
+                    a.cmp(b)
+                })
+            }));
+            assert!(result.is_err(), "limit = {}", limit);
+            let mut arr: Vec<u64> = arr.into_iter().map(|item| *item).collect();
+            arr.sort();
+            let mut expected = keys.clone();
+            expected.sort();
+            assert_eq!(arr, expected);
+        }
+    }
+}
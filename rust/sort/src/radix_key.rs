@@ -0,0 +1,201 @@
+//! 基数排序的键 (Radix Key)
+//!
+//! 基数排序不比较元素，而是逐个“数位”分桶。只要能把键拆成若干字节，
+//! 并且把这些字节当作一个大端无符号整数时，大小关系与键本身的顺序一致，
+//! 就可以对它做基数排序：
+//! - 无符号整数：直接取字节
+//! - 有符号整数：翻转符号位，使负数排在正数之前
+//! - 浮点数：IEEE 754 全序技巧，负数按位取反、非负数翻转符号位，
+//!   得到的顺序与 `f64::total_cmp` 一致（-NaN < -inf < ... < -0.0 < +0.0 < ... < inf < NaN）
+//! - `bool`、`char`：按其整数值
+//! - 元组：按字典序，前面的分量占据高位字节
+
+/// 可以按字节进行基数排序的键
+///
+/// `byte(0)` 是最低位字节，`byte(BYTES - 1)` 是最高位字节。
+pub trait RadixKey: Copy {
+    /// 键的字节数，也就是 LSD 基数排序最多需要的趟数
+    const BYTES: usize;
+
+    /// 第 `index` 个字节（从最低位开始计数），`index < BYTES`
+    fn byte(&self, index: usize) -> u8;
+}
+
+macro_rules! impl_radix_key_unsigned {
+    ($($t:ty),*) => {
+        $(
+            impl RadixKey for $t {
+                const BYTES: usize = std::mem::size_of::<$t>();
+
+                #[inline]
+                fn byte(&self, index: usize) -> u8 {
+                    self.to_le_bytes()[index]
+                }
+            }
+        )*
+    };
+}
+
+impl_radix_key_unsigned!(u8, u16, u32, u64, u128, usize);
+
+macro_rules! impl_radix_key_signed {
+    ($($t:ty => $u:ty),*) => {
+        $(
+            impl RadixKey for $t {
+                const BYTES: usize = std::mem::size_of::<$t>();
+
+                #[inline]
+                fn byte(&self, index: usize) -> u8 {
+                    // 翻转符号位：MIN 变成 0，MAX 变成无符号最大值
+                    let flipped = (*self as $u) ^ (1 << (<$u>::BITS - 1));
+                    flipped.to_le_bytes()[index]
+                }
+            }
+        )*
+    };
+}
+
+impl_radix_key_signed!(i8 => u8, i16 => u16, i32 => u32, i64 => u64, i128 => u128, isize => usize);
+
+macro_rules! impl_radix_key_float {
+    ($($t:ty => $u:ty),*) => {
+        $(
+            impl RadixKey for $t {
+                const BYTES: usize = std::mem::size_of::<$t>();
+
+                #[inline]
+                fn byte(&self, index: usize) -> u8 {
+                    let bits = self.to_bits();
+                    let sign = 1 << (<$u>::BITS - 1);
+                    // 负数：全部取反，绝对值越大排得越靠前
+                    // 非负数：翻转符号位，排在所有负数之后
+                    let ordered = if bits & sign != 0 { !bits } else { bits | sign };
+                    ordered.to_le_bytes()[index]
+                }
+            }
+        )*
+    };
+}
+
+impl_radix_key_float!(f32 => u32, f64 => u64);
+
+impl RadixKey for bool {
+    const BYTES: usize = 1;
+
+    #[inline]
+    fn byte(&self, _index: usize) -> u8 {
+        *self as u8
+    }
+}
+
+impl RadixKey for char {
+    // Unicode 码点最大为 0x10FFFF，三个字节就够了
+    const BYTES: usize = 3;
+
+    #[inline]
+    fn byte(&self, index: usize) -> u8 {
+        (*self as u32).to_le_bytes()[index]
+    }
+}
+
+impl<A: RadixKey, B: RadixKey> RadixKey for (A, B) {
+    const BYTES: usize = A::BYTES + B::BYTES;
+
+    #[inline]
+    fn byte(&self, index: usize) -> u8 {
+        if index < B::BYTES {
+            self.1.byte(index)
+        } else {
+            self.0.byte(index - B::BYTES)
+        }
+    }
+}
+
+impl<A: RadixKey, B: RadixKey, C: RadixKey> RadixKey for (A, B, C) {
+    const BYTES: usize = A::BYTES + B::BYTES + C::BYTES;
+
+    #[inline]
+    fn byte(&self, index: usize) -> u8 {
+        if index < C::BYTES {
+            self.2.byte(index)
+        } else {
+            (self.0, self.1).byte(index - C::BYTES)
+        }
+    }
+}
+
+impl<A: RadixKey, B: RadixKey, C: RadixKey, D: RadixKey> RadixKey for (A, B, C, D) {
+    const BYTES: usize = A::BYTES + B::BYTES + C::BYTES + D::BYTES;
+
+    #[inline]
+    fn byte(&self, index: usize) -> u8 {
+        if index < D::BYTES {
+            self.3.byte(index)
+        } else {
+            (self.0, self.1, self.2).byte(index - D::BYTES)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cmp::Ordering;
+
+    /// 从最高位字节开始逐字节比较
+    fn radix_cmp<T: RadixKey>(a: &T, b: &T) -> Ordering {
+        (0..T::BYTES)
+            .rev()
+            .map(|i| a.byte(i).cmp(&b.byte(i)))
+            .find(|ord| ord.is_ne())
+            .unwrap_or(Ordering::Equal)
+    }
+
+    fn assert_order_preserved<T: RadixKey + std::fmt::Debug>(
+        values: &[T],
+        cmp: impl Fn(&T, &T) -> Ordering,
+    ) {
+        for a in values {
+            for b in values {
+                assert_eq!(radix_cmp(a, b), cmp(a, b), "{:?} vs {:?}", a, b);
+            }
+        }
+    }
+
+    #[test]
+    fn test_integers() {
+        assert_order_preserved(&[0u32, 1, 255, 256, 1_000_000_000, u32::MAX], u32::cmp);
+        assert_order_preserved(&[i8::MIN, -1, 0, 1, i8::MAX], i8::cmp);
+        assert_order_preserved(&[i64::MIN, -300, -1, 0, 1, 300, i64::MAX], i64::cmp);
+        assert_order_preserved(&[0u128, 1, u64::MAX as u128 + 1, u128::MAX], u128::cmp);
+    }
+
+    #[test]
+    fn test_floats() {
+        let values = [
+            f64::NEG_INFINITY,
+            -1e300,
+            -1.5,
+            -f64::MIN_POSITIVE,
+            -0.0,
+            0.0,
+            f64::MIN_POSITIVE,
+            2.5,
+            f64::INFINITY,
+            f64::NAN,
+        ];
+        assert_order_preserved(&values, f64::total_cmp);
+        assert_order_preserved(&[-3.5f32, -0.0, 0.0, 1.0, f32::INFINITY], f32::total_cmp);
+    }
+
+    #[test]
+    fn test_bool_char_tuple() {
+        assert_order_preserved(&[false, true], bool::cmp);
+        assert_order_preserved(&['\0', 'A', 'a', 'é', '中', '\u{10FFFF}'], char::cmp);
+        assert_order_preserved(&[(-1i16, 5u8), (-1, 200), (0, 0), (3, 1)], |a, b| a.cmp(b));
+        assert_order_preserved(
+            &[(1u8, 'b', -2i32), (1, 'a', 7), (0, 'z', 0), (1, 'b', -3)],
+            |a, b| a.cmp(b),
+        );
+    }
+}
@@ -0,0 +1,903 @@
+//! 基数排序 (Radix Sort)
+//!
+//! # 算法原理
+//! 基数排序是一种非比较排序算法：
+//! 1. 从最低位开始，对每一位进行稳定排序（通常使用计数排序）
+//! 2. 依次处理每一位，直到最高位
+//! 3. 排序完成
+//!
+//! 这里的“位”默认是键的一个字节（基数 256），任何实现了 [`RadixKey`] 的类型都可以排序：
+//! 各种整数、浮点数、`bool`、`char` 以及它们组成的元组。
+//!
+//! LSD 版本的优化：
+//! - 一次扫描同时统计所有趟的直方图
+//! - 所有元素在某一位上相同时跳过这一趟（例如小数值的 `u64` 只需要处理低位）
+//! - 只分配一个辅助数组，在原数组和辅助数组之间来回分发，不在每趟重新分配
+//! - 每趟处理的位数可以通过 [`radix_sort_with_bits`] 调整
+//!
+//! 多核机器上可以用 [`radix_sort_parallel`]：每一趟各线程分别统计自己那一段的直方图，
+//! 再并发地把元素分发到输出数组中，结果与 [`radix_sort`] 完全相同。
+//!
+//! # 时间复杂度: O(d * (n + k))
+//! - d: 键的字节数
+//! - n: 元素个数
+//! - k: 基数（256）
+//!
+//! # 空间复杂度: O(n + k)
+//! # 稳定性: 稳定
+
+use crate::cached_key::apply_permutation;
+use crate::insertion_sort::insertion_sort_by;
+use crate::{Order, Parallelism, RadixKey, SortError};
+use std::cmp::Ordering;
+use std::slice;
+use std::sync::Barrier;
+use std::thread;
+
+/// 每个字节的取值个数
+const RADIX: usize = 256;
+
+/// LSD 基数排序默认每趟处理的位数
+pub const DEFAULT_RADIX_BITS: u32 = 8;
+
+/// 每趟处理位数的上限，对应 65536 个桶
+pub const MAX_RADIX_BITS: u32 = 16;
+
+/// 基数排序 - LSD (Least Significant Digit) 版本
+pub fn radix_sort<T: RadixKey>(arr: &mut [T]) {
+    radix_sort_with_order(arr, Order::Ascending);
+}
+
+/// 按指定方向排序的 LSD 基数排序
+///
+/// 降序时每一位都使用 `radix - 1 - digit` 作为桶号，每一趟依然是稳定的分发，
+/// 所以整体结果是稳定的降序，而不是先升序再反转。
+pub fn radix_sort_with_order<T: RadixKey>(arr: &mut [T], order: Order) {
+    radix_sort_with_bits(arr, DEFAULT_RADIX_BITS, order);
+}
+
+/// 每趟处理 `radix_bits` 位（基数为 `2^radix_bits`）的 LSD 基数排序
+///
+/// 位数越大趟数越少，但直方图越大；8 位在大多数情况下是较好的折中，
+/// 数据量很大时 11 位或 16 位可能更快。
+///
+/// # Panics
+/// `radix_bits` 不在 `1..=MAX_RADIX_BITS` 范围内时 panic；需要自己处理时使用 [`try_radix_sort_with_bits`]
+pub fn radix_sort_with_bits<T: RadixKey>(arr: &mut [T], radix_bits: u32, order: Order) {
+    if let Err(err) = try_radix_sort_with_bits(arr, radix_bits, order) {
+        panic!("{}", err);
+    }
+}
+
+/// 带参数检查的 LSD 基数排序
+///
+/// `radix_bits` 不在 `1..=MAX_RADIX_BITS` 范围内时返回 [`SortError::InvalidRadixBits`]。
+/// 键按字节处理，不会像按十进制位取 `exp *= 10` 那样溢出；浮点数中的 NaN
+/// 按 `f64::total_cmp` 的顺序排在两端，所以不需要额外检查。
+pub fn try_radix_sort_with_bits<T: RadixKey>(
+    arr: &mut [T],
+    radix_bits: u32,
+    order: Order,
+) -> Result<(), SortError> {
+    if !(1..=MAX_RADIX_BITS).contains(&radix_bits) {
+        return Err(SortError::InvalidRadixBits {
+            bits: radix_bits,
+            max: MAX_RADIX_BITS,
+        });
+    }
+    lsd_radix_sort(arr, |key| *key, radix_bits, order);
+    Ok(())
+}
+
+/// 按键排序的稳定 LSD 基数排序：用 `key` 从元素中取出整数键，移动整个元素
+///
+/// 例如按 `user_id` 排序 `(user_id, row)` 记录。每个元素的键只计算一次，
+/// 先对 `(key, index)` 做基数排序，再按下标原地置换原数组，
+/// 所以元素不需要实现 `Copy` 或 `Clone`。键相同的元素保持原有顺序。
+///
+/// # 示例
+/// ```
+/// use sort::radix_sort::radix_sort_by_key;
+///
+/// let mut rows = vec![(3u64, "c1"), (1, "a1"), (3, "c2"), (2, "b1"), (1, "a2")];
+/// radix_sort_by_key(&mut rows, |row| row.0);
+/// assert_eq!(rows, vec![(1, "a1"), (1, "a2"), (2, "b1"), (3, "c1"), (3, "c2")]);
+/// ```
+pub fn radix_sort_by_key<T, K, F>(arr: &mut [T], key: F)
+where
+    K: RadixKey,
+    F: FnMut(&T) -> K,
+{
+    radix_sort_by_key_with_order(arr, key, Order::Ascending);
+}
+
+/// 按指定方向、按键排序的稳定 LSD 基数排序
+pub fn radix_sort_by_key_with_order<T, K, F>(arr: &mut [T], key: F, order: Order)
+where
+    K: RadixKey,
+    F: FnMut(&T) -> K,
+{
+    if arr.len() <= 1 {
+        return;
+    }
+
+    let mut indices: Vec<(K, usize)> = arr
+        .iter()
+        .map(key)
+        .enumerate()
+        .map(|(i, k)| (k, i))
+        .collect();
+
+    // 只按键排序，基数排序是稳定的，键相同的下标保持升序
+    lsd_radix_sort(&mut indices, |pair| pair.0, DEFAULT_RADIX_BITS, order);
+
+    apply_permutation(arr, &mut indices);
+}
+
+/// LSD 基数排序的核心：用 `key` 取出每个元素的键，按键稳定排序
+fn lsd_radix_sort<E, K, G>(arr: &mut [E], key: G, radix_bits: u32, order: Order)
+where
+    E: Copy,
+    K: RadixKey,
+    G: Fn(&E) -> K,
+{
+    debug_assert!((1..=MAX_RADIX_BITS).contains(&radix_bits));
+
+    let n = arr.len();
+    if n <= 1 {
+        return;
+    }
+
+    let radix = 1usize << radix_bits;
+    let passes = (K::BYTES * 8).div_ceil(radix_bits as usize);
+    let digit = |elem: &E, pass: usize| {
+        let d = extract_digit(&key(elem), pass * radix_bits as usize, radix_bits);
+        match order {
+            Order::Ascending => d,
+            Order::Descending => radix - 1 - d,
+        }
+    };
+
+    // 一次扫描统计所有趟的直方图，counts[pass * radix + d] 是第 pass 趟数字 d 的个数
+    let mut counts = vec![0usize; passes * radix];
+    for elem in arr.iter() {
+        for pass in 0..passes {
+            counts[pass * radix + digit(elem, pass)] += 1;
+        }
+    }
+
+    // 原数组和辅助数组轮流作为源和目标，in_buffer 表示当前数据在辅助数组中
+    let mut buffer: Vec<E> = Vec::new();
+    let mut in_buffer = false;
+
+    for pass in 0..passes {
+        let count = &mut counts[pass * radix..(pass + 1) * radix];
+
+        // 所有元素在这一位上相同，这一趟不会改变顺序
+        if count.contains(&n) {
+            continue;
+        }
+
+        // 计数转换为每个桶的起始位置
+        let mut offset = 0;
+        for c in count.iter_mut() {
+            let cnt = *c;
+            *c = offset;
+            offset += cnt;
+        }
+
+        if buffer.is_empty() {
+            buffer = arr.to_vec();
+        }
+        let (src, dst): (&[E], &mut [E]) = if in_buffer {
+            (&buffer, arr)
+        } else {
+            (arr, &mut buffer)
+        };
+
+        // 正向遍历分发，相同数字的元素保持原有顺序
+        for elem in src {
+            let d = digit(elem, pass);
+            dst[count[d]] = *elem;
+            count[d] += 1;
+        }
+        in_buffer = !in_buffer;
+    }
+
+    if in_buffer {
+        arr.copy_from_slice(&buffer);
+    }
+}
+
+/// 并行 LSD 基数排序，线程数取 [`Parallelism::default`]
+pub fn radix_sort_parallel<T: RadixKey + Send + Sync>(arr: &mut [T]) {
+    radix_sort_parallel_with_order(arr, Order::Ascending);
+}
+
+/// 按指定方向排序的并行 LSD 基数排序
+pub fn radix_sort_parallel_with_order<T: RadixKey + Send + Sync>(arr: &mut [T], order: Order) {
+    radix_sort_with_parallelism(arr, Parallelism::default(), order);
+}
+
+/// 指定线程数和顺序排序阈值的并行 LSD 基数排序
+///
+/// 数组分成最多 `threads` 段（每段不短于 `sequential_cutoff`）。先用一次并行扫描统计
+/// 各段所有趟的直方图，所有元素在某个字节上相同的趟直接跳过，全部跳过时不分配辅助数组。
+/// 之后每一趟（一个字节）分三步：
+/// 1. 在调用方线程中按“数字优先、段号其次”的顺序求前缀和，
+///    得到每个线程、每个数字在输出数组中的起始位置
+/// 2. 各线程按顺序把自己那一段分发到输出数组，写入的位置互不重叠
+/// 3. 所有线程写完后，各线程统计输出数组中自己那一段在下一趟的直方图
+///
+/// 同一个数字中，前面各段的元素排在后面各段之前，段内保持原有顺序，
+/// 所以每一趟都是稳定的，结果与线程数无关，和 [`radix_sort`] 完全相同。
+///
+/// # 示例
+/// ```
+/// use sort::radix_sort::radix_sort_with_parallelism;
+/// use sort::{Order, Parallelism};
+///
+/// let mut arr: Vec<u64> = (0..100_000).map(|i| (i * 7919) % 100_003).collect();
+/// let parallelism = Parallelism::new(4).with_sequential_cutoff(4096);
+/// radix_sort_with_parallelism(&mut arr, parallelism, Order::Ascending);
+/// assert!(arr.windows(2).all(|w| w[0] <= w[1]));
+/// ```
+pub fn radix_sort_with_parallelism<T: RadixKey + Send + Sync>(
+    arr: &mut [T],
+    parallelism: Parallelism,
+    order: Order,
+) {
+    parallel_lsd_radix_sort(arr, |key| *key, parallelism, order);
+}
+
+/// 并行 LSD 基数排序的核心，每趟处理一个字节
+fn parallel_lsd_radix_sort<E, K, G>(arr: &mut [E], key: G, parallelism: Parallelism, order: Order)
+where
+    E: Copy + Send + Sync,
+    K: RadixKey,
+    G: Fn(&E) -> K + Sync,
+{
+    let n = arr.len();
+    let threads = parallelism.threads.max(1);
+    let cutoff = parallelism.sequential_cutoff.max(1);
+    if threads == 1 || n <= cutoff {
+        lsd_radix_sort(arr, key, DEFAULT_RADIX_BITS, order);
+        return;
+    }
+
+    let chunk_len = n.div_ceil(threads.min(n.div_ceil(cutoff)));
+    let chunks = n.div_ceil(chunk_len);
+    let digit = |elem: &E, pass: usize| {
+        let d = key(elem).byte(pass) as usize;
+        match order {
+            Order::Ascending => d,
+            Order::Descending => RADIX - 1 - d,
+        }
+    };
+    let digit = &digit;
+
+    // 一次并行扫描统计每段所有趟的直方图，initial[段][pass][d]
+    let initial: Vec<Vec<[usize; RADIX]>> = thread::scope(|s| {
+        let handles: Vec<_> = arr
+            .chunks(chunk_len)
+            .map(|chunk| {
+                s.spawn(move || {
+                    let mut histograms = vec![[0usize; RADIX]; K::BYTES];
+                    for elem in chunk {
+                        for (pass, histogram) in histograms.iter_mut().enumerate() {
+                            histogram[digit(elem, pass)] += 1;
+                        }
+                    }
+                    histograms
+                })
+            })
+            .collect();
+        handles.into_iter().map(|h| h.join().unwrap()).collect()
+    });
+
+    // 所有元素在某一位上相同，这一趟不会改变顺序
+    let passes: Vec<usize> = (0..K::BYTES)
+        .filter(|&pass| !(0..RADIX).any(|d| initial.iter().map(|h| h[pass][d]).sum::<usize>() == n))
+        .collect();
+    let Some(&first) = passes.first() else {
+        return;
+    };
+
+    // 各段的直方图与元素的位置有关，元素移动之后只有总数不变，
+    // 所以初始扫描的分段直方图只能用于第一趟，之后每趟的直方图在上一趟分发之后统计
+    let mut histograms: Vec<[usize; RADIX]> = initial.iter().map(|h| h[first]).collect();
+
+    // 原数组和辅助数组轮流作为源和目标，in_buffer 表示当前数据在辅助数组中。
+    // 至少有一趟需要分发时才分配辅助数组
+    let mut buffer = arr.to_vec();
+    let mut in_buffer = false;
+
+    for (i, &pass) in passes.iter().enumerate() {
+        let next_pass = passes.get(i + 1).copied();
+        let (src, dst): (&[E], &mut [E]) = if in_buffer {
+            (&buffer, arr)
+        } else {
+            (arr, &mut buffer)
+        };
+
+        // 数字优先、段号其次求前缀和，计数转换为每段每个数字的起始位置
+        let mut offset = 0;
+        for d in 0..RADIX {
+            for histogram in histograms.iter_mut() {
+                let count = histogram[d];
+                histogram[d] = offset;
+                offset += count;
+            }
+        }
+
+        // 各线程并发分发，正向遍历保证段内相同数字的元素保持原有顺序。
+        // 这一趟的目标就是下一趟的源：等所有线程写完后，各线程在同一个线程中
+        // 接着统计目标数组中自己那一段在下一趟的直方图
+        let out = SharedOutput(dst.as_mut_ptr());
+        let written = &Barrier::new(chunks);
+        histograms = thread::scope(|s| {
+            let handles: Vec<_> = src
+                .chunks(chunk_len)
+                .zip(histograms)
+                .enumerate()
+                .map(|(index, (chunk, mut offsets))| {
+                    s.spawn(move || {
+                        // 分发中途 panic 时也要到达屏障，否则其他线程会一直等待
+                        let arrive = ArriveOnDrop(next_pass.map(|_| written));
+                        for elem in chunk {
+                            let d = digit(elem, pass);
+                            // SAFETY: 各段各数字的位置区间互不重叠，且都在 `dst` 的范围内
+                            unsafe { out.get().add(offsets[d]).write(*elem) };
+                            offsets[d] += 1;
+                        }
+
+                        drop(arrive);
+
+                        let mut histogram = [0usize; RADIX];
+                        if let Some(next_pass) = next_pass {
+                            // SAFETY: 其他线程都已写完（或已经 panic，这一趟的结果会被丢弃），
+                            // 之后只读取，各线程读取的段互不重叠。`dst` 来自原数组或
+                            // `to_vec`，没写到的位置也是有效的元素
+                            let next = unsafe {
+                                slice::from_raw_parts(out.get().add(index * chunk_len), chunk.len())
+                            };
+                            for elem in next {
+                                histogram[digit(elem, next_pass)] += 1;
+                            }
+                        }
+                        histogram
+                    })
+                })
+                .collect();
+            handles.into_iter().map(|h| h.join().unwrap()).collect()
+        });
+        in_buffer = !in_buffer;
+    }
+
+    if in_buffer {
+        arr.copy_from_slice(&buffer);
+    }
+}
+
+/// 析构时到达屏障，包括 panic 展开时
+struct ArriveOnDrop<'a>(Option<&'a Barrier>);
+
+impl Drop for ArriveOnDrop<'_> {
+    fn drop(&mut self) {
+        if let Some(barrier) = self.0.take() {
+            barrier.wait();
+        }
+    }
+}
+
+/// 多个线程同时写入的输出数组，每个线程只写自己负责的位置
+#[derive(Clone, Copy)]
+struct SharedOutput<E>(*mut E);
+
+// SAFETY: 只用于在线程间传递输出数组的地址，写入的位置互不重叠由使用者保证
+unsafe impl<E: Send> Send for SharedOutput<E> {}
+unsafe impl<E: Send> Sync for SharedOutput<E> {}
+
+impl<E> SharedOutput<E> {
+    /// 通过方法取指针，使闭包捕获整个 `SharedOutput` 而不是其中的裸指针
+    fn get(self) -> *mut E {
+        self.0
+    }
+}
+
+/// 取出键中从第 `shift` 位开始的 `bits` 位（从最低位开始计数）
+fn extract_digit<T: RadixKey>(key: &T, shift: usize, bits: u32) -> usize {
+    let first = shift / 8;
+    let last = ((shift + bits as usize - 1) / 8).min(T::BYTES - 1);
+
+    // bits 最多 16 位，最多跨越 3 个字节
+    let mut value = 0usize;
+    for index in (first..=last).rev() {
+        value = (value << 8) | key.byte(index) as usize;
+    }
+    (value >> (shift % 8)) & ((1 << bits) - 1)
+}
+
+/// 根据第 `index` 个字节（从最低位开始）进行计数排序
+pub fn counting_sort_by_byte<T: RadixKey>(arr: &mut [T], index: usize) {
+    counting_sort_by_byte_with_order(arr, index, Order::Ascending);
+}
+
+fn counting_sort_by_byte_with_order<T: RadixKey>(arr: &mut [T], index: usize, order: Order) {
+    let mut output = arr.to_vec();
+    let mut count = [0usize; RADIX];
+
+    // 统计当前字节的取值出现次数
+    for key in arr.iter() {
+        count[digit_at(key, index, order)] += 1;
+    }
+
+    // 累加计数
+    for i in 1..RADIX {
+        count[i] += count[i - 1];
+    }
+
+    // 反向遍历，构建输出数组（保证稳定性）
+    for key in arr.iter().rev() {
+        let digit = digit_at(key, index, order);
+        count[digit] -= 1;
+        output[count[digit]] = *key;
+    }
+
+    // 复制回原数组
+    arr.copy_from_slice(&output);
+}
+
+/// 取键的第 `index` 个字节作为桶号，降序时取反
+fn digit_at<T: RadixKey>(key: &T, index: usize, order: Order) -> usize {
+    let byte = key.byte(index);
+    match order {
+        Order::Ascending => byte as usize,
+        Order::Descending => (u8::MAX - byte) as usize,
+    }
+}
+
+/// 基数排序 - MSD (Most Significant Digit) 版本
+/// 从最高字节开始，递归处理，使用原地的 [`american_flag_sort`]
+pub fn radix_sort_msd<T: RadixKey>(arr: &mut [T]) {
+    radix_sort_msd_with_order(arr, Order::Ascending);
+}
+
+/// 按指定方向排序的 MSD 基数排序
+pub fn radix_sort_msd_with_order<T: RadixKey>(arr: &mut [T], order: Order) {
+    american_flag_sort_with_order(arr, order);
+}
+
+/// 桶内元素不超过这个数量时改用插入排序
+const AMERICAN_FLAG_INSERTION_THRESHOLD: usize = 32;
+
+/// 美国国旗排序 (American Flag Sort) - 原地 MSD 基数排序
+///
+/// 与分桶后再复制回来的 MSD 不同，它先统计当前字节的直方图，得到每个桶在原数组中的区间，
+/// 然后通过交换把每个元素直接放进所属的桶，不需要额外的桶数组。
+/// 每个桶再按下一个字节递归处理，小桶改用插入排序。
+///
+/// # 空间复杂度: O(k * d) - 只有每层递归的计数数组
+/// # 稳定性: 不稳定（交换会打乱相等键的顺序）
+pub fn american_flag_sort<T: RadixKey>(arr: &mut [T]) {
+    american_flag_sort_with_order(arr, Order::Ascending);
+}
+
+/// 按指定方向排序的美国国旗排序
+pub fn american_flag_sort_with_order<T: RadixKey>(arr: &mut [T], order: Order) {
+    if arr.len() <= 1 {
+        return;
+    }
+
+    american_flag_sort_recursive(arr, T::BYTES, order);
+}
+
+/// `remaining` 是尚未处理的字节数，当前处理第 `remaining - 1` 个字节
+fn american_flag_sort_recursive<T: RadixKey>(arr: &mut [T], remaining: usize, order: Order) {
+    let n = arr.len();
+    if n <= 1 || remaining == 0 {
+        return;
+    }
+
+    // 小桶：高位字节都相同，只需比较剩余的字节
+    if n <= AMERICAN_FLAG_INSERTION_THRESHOLD {
+        insertion_sort_by(arr, |a, b| order.apply(compare_bytes(a, b, remaining)));
+        return;
+    }
+
+    let index = remaining - 1;
+
+    // 统计当前字节的直方图
+    let mut count = [0usize; RADIX];
+    for key in arr.iter() {
+        count[digit_at(key, index, order)] += 1;
+    }
+
+    // 所有元素在这个字节上相同，直接处理下一个字节
+    if count.contains(&n) {
+        american_flag_sort_recursive(arr, index, order);
+        return;
+    }
+
+    // next[d] 是桶 d 中下一个待放置的位置，end[d] 是桶 d 的结束位置
+    let mut next = [0usize; RADIX];
+    let mut end = [0usize; RADIX];
+    let mut offset = 0;
+    for d in 0..RADIX {
+        next[d] = offset;
+        offset += count[d];
+        end[d] = offset;
+    }
+
+    // 逐个桶处理：把不属于当前桶的元素交换到它所属桶的下一个位置
+    for d in 0..RADIX {
+        while next[d] < end[d] {
+            let target = digit_at(&arr[next[d]], index, order);
+            if target == d {
+                next[d] += 1;
+            } else {
+                arr.swap(next[d], next[target]);
+                next[target] += 1;
+            }
+        }
+    }
+
+    // 递归处理每个桶
+    let mut start = 0;
+    for &len in count.iter() {
+        american_flag_sort_recursive(&mut arr[start..start + len], index, order);
+        start += len;
+    }
+}
+
+/// 从第 `remaining - 1` 个字节开始向低位逐字节比较
+fn compare_bytes<T: RadixKey>(a: &T, b: &T, remaining: usize) -> Ordering {
+    for index in (0..remaining).rev() {
+        match a.byte(index).cmp(&b.byte(index)) {
+            Ordering::Equal => continue,
+            ord => return ord,
+        }
+    }
+    Ordering::Equal
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{random_vec, XorShift};
+    use std::panic::{self, AssertUnwindSafe};
+    use std::sync::atomic::{self, AtomicUsize};
+
+    #[test]
+    fn test_radix_sort() {
+        let mut arr: Vec<u32> = vec![170, 45, 75, 90, 802, 24, 2, 66];
+        radix_sort(&mut arr);
+        assert_eq!(arr, vec![2, 24, 45, 66, 75, 90, 170, 802]);
+    }
+
+    #[test]
+    fn test_radix_sort_msd() {
+        let mut arr: Vec<u32> = vec![170, 45, 75, 90, 802, 24, 2, 66];
+        radix_sort_msd(&mut arr);
+        assert_eq!(arr, vec![2, 24, 45, 66, 75, 90, 170, 802]);
+    }
+
+    #[test]
+    fn test_empty() {
+        let mut arr: Vec<u32> = vec![];
+        radix_sort(&mut arr);
+        assert_eq!(arr, vec![]);
+    }
+
+    #[test]
+    fn test_single() {
+        let mut arr: Vec<u32> = vec![42];
+        radix_sort(&mut arr);
+        assert_eq!(arr, vec![42]);
+    }
+
+    #[test]
+    fn test_same_digits() {
+        let mut arr: Vec<u32> = vec![111, 222, 111, 333, 222];
+        radix_sort(&mut arr);
+        assert_eq!(arr, vec![111, 111, 222, 222, 333]);
+    }
+
+    #[test]
+    fn test_descending() {
+        let expected = vec![802, 170, 90, 75, 66, 45, 24, 2];
+
+        let mut arr: Vec<u32> = vec![170, 45, 75, 90, 802, 24, 2, 66];
+        radix_sort_with_order(&mut arr, Order::Descending);
+        assert_eq!(arr, expected);
+
+        let mut arr: Vec<u32> = vec![170, 45, 75, 90, 802, 24, 2, 66];
+        radix_sort_msd_with_order(&mut arr, Order::Descending);
+        assert_eq!(arr, expected);
+    }
+
+    #[test]
+    fn test_large_values_do_not_overflow() {
+        let mut arr: Vec<u32> = vec![u32::MAX, 4_000_000_000, 1_000_000_000, 0, 999_999_999];
+        radix_sort(&mut arr);
+        assert_eq!(
+            arr,
+            vec![0, 999_999_999, 1_000_000_000, 4_000_000_000, u32::MAX]
+        );
+
+        let mut arr: Vec<u32> = vec![u32::MAX, 4_000_000_000, 1_000_000_000, 0, 999_999_999];
+        radix_sort_msd(&mut arr);
+        assert_eq!(
+            arr,
+            vec![0, 999_999_999, 1_000_000_000, 4_000_000_000, u32::MAX]
+        );
+    }
+
+    #[test]
+    fn test_signed_integers() {
+        let mut arr: Vec<i64> = vec![5, i64::MIN, -1, 0, i64::MAX, -300, 300];
+        radix_sort(&mut arr);
+        assert_eq!(arr, vec![i64::MIN, -300, -1, 0, 5, 300, i64::MAX]);
+
+        let mut arr: Vec<i8> = vec![5, -128, -1, 0, 127, -3];
+        radix_sort_msd(&mut arr);
+        assert_eq!(arr, vec![-128, -3, -1, 0, 5, 127]);
+    }
+
+    #[test]
+    fn test_floats() {
+        let mut arr = vec![
+            3.5,
+            -0.0,
+            f64::INFINITY,
+            -2.25,
+            0.0,
+            f64::NEG_INFINITY,
+            1e-300,
+        ];
+        radix_sort(&mut arr);
+        let mut expected = arr.clone();
+        expected.sort_by(f64::total_cmp);
+        assert_eq!(arr, expected);
+        assert!(arr[2].is_sign_negative() && arr[3].is_sign_positive());
+
+        let mut arr: Vec<f32> = vec![1.5, -7.0, 0.25, -0.5];
+        radix_sort_msd_with_order(&mut arr, Order::Descending);
+        assert_eq!(arr, vec![1.5, 0.25, -0.5, -7.0]);
+    }
+
+    #[test]
+    fn test_other_keys() {
+        let mut flags = vec![true, false, true, false];
+        radix_sort(&mut flags);
+        assert_eq!(flags, vec![false, false, true, true]);
+
+        let mut chars = vec!['中', 'b', 'A', 'é', 'a'];
+        radix_sort_msd(&mut chars);
+        assert_eq!(chars, vec!['A', 'a', 'b', 'é', '中']);
+
+        let mut pairs: Vec<(i32, u8)> = vec![(2, 1), (-1, 9), (2, 0), (-1, 3), (0, 5)];
+        radix_sort(&mut pairs);
+        assert_eq!(pairs, vec![(-1, 3), (-1, 9), (0, 5), (2, 0), (2, 1)]);
+    }
+
+    #[test]
+    fn test_radix_bits() {
+        let input = random_vec(2000, u64::MAX, 42);
+        let mut expected = input.clone();
+        expected.sort();
+
+        for bits in 1..=MAX_RADIX_BITS {
+            let mut arr = input.clone();
+            radix_sort_with_bits(&mut arr, bits, Order::Ascending);
+            assert_eq!(arr, expected, "radix_bits = {}", bits);
+        }
+    }
+
+    #[test]
+    fn test_skipped_passes() {
+        // 高位字节全部相同，只有最低字节需要排序
+        let input: Vec<u64> = random_vec(500, 256, 7)
+            .iter()
+            .map(|x| x | 0xABCD_0000_0000)
+            .collect();
+        let mut expected = input.clone();
+        expected.sort();
+
+        let mut arr = input.clone();
+        radix_sort(&mut arr);
+        assert_eq!(arr, expected);
+
+        // 所有元素相同，每一趟都被跳过
+        let mut same = vec![7u32; 100];
+        radix_sort_with_bits(&mut same, 11, Order::Descending);
+        assert_eq!(same, vec![7u32; 100]);
+    }
+
+    #[test]
+    fn test_tuple_descending_with_bits() {
+        // 位数不整除键长（24 位）时，最后一趟只包含部分位
+        let input: Vec<(u8, u16)> = random_vec(300, 8, 3)
+            .iter()
+            .enumerate()
+            .map(|(i, &k)| (k as u8, i as u16))
+            .collect();
+        let mut expected = input.clone();
+        expected.sort_by(|a, b| b.0.cmp(&a.0).then(b.1.cmp(&a.1)));
+
+        for bits in [3, 8, 13] {
+            let mut arr = input.clone();
+            radix_sort_with_bits(&mut arr, bits, Order::Descending);
+            assert_eq!(arr, expected, "radix_bits = {}", bits);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_invalid_radix_bits() {
+        radix_sort_with_bits(&mut [3u32, 1, 2], 0, Order::Ascending);
+    }
+
+    #[test]
+    fn test_try_radix_sort_with_bits() {
+        let mut arr = vec![3u32, 1, 2];
+        assert_eq!(
+            try_radix_sort_with_bits(&mut arr, MAX_RADIX_BITS + 1, Order::Ascending),
+            Err(SortError::InvalidRadixBits {
+                bits: MAX_RADIX_BITS + 1,
+                max: MAX_RADIX_BITS
+            })
+        );
+        assert_eq!(arr, vec![3, 1, 2]);
+
+        // 以前按十进制位取数字时 exp 会溢出
+        let mut arr = vec![u64::MAX, 0, u64::MAX - 1, 1];
+        assert_eq!(
+            try_radix_sort_with_bits(&mut arr, 11, Order::Ascending),
+            Ok(())
+        );
+        assert_eq!(arr, vec![0, 1, u64::MAX - 1, u64::MAX]);
+    }
+
+    #[test]
+    fn test_american_flag_sort() {
+        for (len, bound) in [(1000, u64::MAX), (1000, 300), (5000, 70_000), (40, 10)] {
+            let input = random_vec(len, bound, bound ^ len as u64);
+            let mut expected = input.clone();
+            expected.sort();
+
+            let mut arr = input.clone();
+            american_flag_sort(&mut arr);
+            assert_eq!(arr, expected);
+
+            expected.reverse();
+            let mut arr = input.clone();
+            american_flag_sort_with_order(&mut arr, Order::Descending);
+            assert_eq!(arr, expected);
+        }
+    }
+
+    #[test]
+    fn test_american_flag_sort_signed_tuples() {
+        let mut rng = XorShift::new(99);
+        let input: Vec<(i16, char)> = (0..600)
+            .map(|_| {
+                let x = rng.next_u64();
+                (x as i16 % 50, char::from(b'a' + (x >> 20) as u8 % 26))
+            })
+            .collect();
+        let mut expected = input.clone();
+        expected.sort();
+
+        let mut arr = input;
+        american_flag_sort(&mut arr);
+        assert_eq!(arr, expected);
+    }
+
+    #[test]
+    fn test_radix_sort_by_key_is_stable() {
+        // 行数据是 String，不能 Copy；按 user_id 排序，同一用户的行保持原有顺序
+        let ids = random_vec(400, 20, 11);
+        let input: Vec<(u64, String)> = ids
+            .iter()
+            .enumerate()
+            .map(|(row, &id)| (id, format!("row{}", row)))
+            .collect();
+
+        let mut expected = input.clone();
+        expected.sort_by_key(|r| r.0);
+        let mut arr = input.clone();
+        radix_sort_by_key(&mut arr, |r| r.0);
+        assert_eq!(arr, expected);
+
+        let mut expected = input.clone();
+        expected.sort_by_key(|r| std::cmp::Reverse(r.0));
+        let mut arr = input;
+        radix_sort_by_key_with_order(&mut arr, |r| r.0 as u32, Order::Descending);
+        assert_eq!(arr, expected);
+    }
+
+    #[test]
+    fn test_radix_sort_parallel() {
+        for threads in [1, 2, 3, 8] {
+            let parallelism = Parallelism::new(threads).with_sequential_cutoff(64);
+            for len in [0, 1, 64, 65, 1000, 4321] {
+                let input = random_vec(len, u64::MAX, len as u64 + threads as u64);
+                for order in [Order::Ascending, Order::Descending] {
+                    let mut expected = input.clone();
+                    radix_sort_with_order(&mut expected, order);
+                    let mut arr = input.clone();
+                    radix_sort_with_parallelism(&mut arr, parallelism, order);
+                    assert_eq!(arr, expected, "threads = {}, len = {}", threads, len);
+                }
+
+                // 有符号整数和浮点数，高位字节全部相同时跳过对应的趟
+                let mut signed: Vec<i32> = input.iter().map(|&x| (x as i32) >> 20).collect();
+                let mut expected = signed.clone();
+                expected.sort();
+                radix_sort_with_parallelism(&mut signed, parallelism, Order::Ascending);
+                assert_eq!(signed, expected);
+
+                let mut floats: Vec<f64> = input.iter().map(|&x| x as f64 - 1e19).collect();
+                let mut expected = floats.clone();
+                expected.sort_by(f64::total_cmp);
+                radix_sort_with_parallelism(&mut floats, parallelism, Order::Ascending);
+                assert_eq!(floats, expected);
+            }
+        }
+
+        let input = random_vec(300_000, 1_000_000, 61);
+        let mut expected = input.clone();
+        expected.sort();
+        let mut arr = input;
+        radix_sort_parallel(&mut arr);
+        assert_eq!(arr, expected);
+    }
+
+    #[test]
+    fn test_parallel_panic_in_key() {
+        // 键函数在初始扫描、分发和统计下一趟直方图时 panic，都应该传播出来而不是死锁
+        let n = 4000;
+        let input: Vec<u32> = random_vec(n, u32::MAX as u64, 63)
+            .into_iter()
+            .map(|x| x as u32)
+            .collect();
+        let parallelism = Parallelism::new(4).with_sequential_cutoff(100);
+        for limit in [n, 4 * n + n / 2, 5 * n + n / 2, 6 * n + n / 2] {
+            let calls = AtomicUsize::new(0);
+            let mut arr = input.clone();
+            let result = panic::catch_unwind(AssertUnwindSafe(|| {
+                parallel_lsd_radix_sort(
+                    &mut arr,
+                    |x| {
+                        assert!(
+                            calls.fetch_add(1, atomic::Ordering::Relaxed) < limit,
+                            "键函数 panic"
+                        );
+                        *x
+                    },
+                    parallelism,
+                    Order::Ascending,
+                )
+            }));
+            assert!(result.is_err(), "limit = {}", limit);
+        }
+    }
+
+    #[test]
+    fn test_parallel_is_stable() {
+        // 只按第一个分量排序，第二个分量记录原来的位置
+        let keys = random_vec(10_000, 50, 62);
+        let mut pairs: Vec<(u8, u32)> = keys.iter().zip(0..).map(|(&k, i)| (k as u8, i)).collect();
+        let parallelism = Parallelism::new(4).with_sequential_cutoff(100);
+        parallel_lsd_radix_sort(&mut pairs, |pair| pair.0, parallelism, Order::Descending);
+        assert!(pairs
+            .windows(2)
+            .all(|w| w[0].0 > w[1].0 || (w[0].0 == w[1].0 && w[0].1 < w[1].1)));
+
+        // 键全部相同时所有趟都跳过，元素保持原有顺序
+        let mut pairs: Vec<(u32, u32)> = (0..10_000).map(|i| (7, i)).collect();
+        parallel_lsd_radix_sort(&mut pairs, |pair| pair.0, parallelism, Order::Ascending);
+        assert!(pairs.iter().zip(0..).all(|(pair, i)| pair.1 == i));
+    }
+}
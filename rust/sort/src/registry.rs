@@ -0,0 +1,496 @@
+//! 排序算法注册表
+//!
+//! 把各模块中的排序函数包装成 [`SortAlgorithm`]，按名称登记，
+//! 方便调用方在运行时列出或挑选算法（例如基准测试、命令行参数）。
+//!
+//! 比较排序适用于任意 `T: Ord`（不需要 `Clone`），由 [`comparison_sorts`] 给出；
+//! 多线程的并行排序还要求 `T: Send`，由 [`parallel_sorts`] 和 [`parallel_radix_sorts`] 给出；
+//! 基数排序适用于任意 [`RadixKey`]，由 [`radix_sorts`] 给出；
+//! 其余非比较排序只能处理特定的元素类型，所以按元素类型分别提供注册表，
+//! 其中也包含了全部比较排序和基数排序。
+
+use crate::{
+    block_merge_sort, bubble_sort, bucket_sort, counting_sort, heap_sort, insertion_sort,
+    intro_sort, merge_sort, pdq_sort, quick_sort, radix_sort, selection_sort, shell_sort,
+    smooth_sort, tim_sort,
+};
+use crate::{Order, RadixKey, SortAlgorithm};
+
+/// 以函数指针形式登记的排序算法
+pub struct Algorithm<T> {
+    name: &'static str,
+    stable: bool,
+    sort_fn: fn(&mut [T], Order),
+}
+
+impl<T> Algorithm<T> {
+    /// 用名称、稳定性和排序函数构造一个算法
+    pub const fn new(name: &'static str, stable: bool, sort_fn: fn(&mut [T], Order)) -> Self {
+        Algorithm {
+            name,
+            stable,
+            sort_fn,
+        }
+    }
+}
+
+// 函数指针总是可以复制，不需要 `T: Clone`，所以手写而不是 derive
+impl<T> Clone for Algorithm<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Algorithm<T> {}
+
+impl<T> std::fmt::Debug for Algorithm<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Algorithm")
+            .field("name", &self.name)
+            .field("stable", &self.stable)
+            .finish()
+    }
+}
+
+impl<T> SortAlgorithm<T> for Algorithm<T> {
+    fn name(&self) -> &'static str {
+        self.name
+    }
+
+    fn is_stable(&self) -> bool {
+        self.stable
+    }
+
+    fn sort_with_order(&self, arr: &mut [T], order: Order) {
+        (self.sort_fn)(arr, order)
+    }
+}
+
+/// 按名称登记的一组排序算法，保持登记顺序
+#[derive(Debug, Clone)]
+pub struct Registry<T> {
+    algorithms: Vec<Algorithm<T>>,
+}
+
+impl<T> Registry<T> {
+    /// 创建空的注册表
+    pub fn new() -> Self {
+        Registry {
+            algorithms: Vec::new(),
+        }
+    }
+
+    /// 登记一个算法
+    ///
+    /// # Panics
+    /// 名称已被登记时 panic
+    pub fn register(&mut self, name: &'static str, stable: bool, sort_fn: fn(&mut [T], Order)) {
+        assert!(self.get(name).is_none(), "排序算法 `{}` 重复登记", name);
+        self.algorithms.push(Algorithm::new(name, stable, sort_fn));
+    }
+
+    /// 按名称查找算法
+    pub fn get(&self, name: &str) -> Option<&Algorithm<T>> {
+        self.algorithms.iter().find(|a| a.name == name)
+    }
+
+    /// 按登记顺序列出所有算法名称
+    pub fn names(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.algorithms.iter().map(|a| a.name)
+    }
+
+    /// 按登记顺序遍历所有算法
+    pub fn iter(&self) -> std::slice::Iter<'_, Algorithm<T>> {
+        self.algorithms.iter()
+    }
+
+    /// 已登记的算法数量
+    pub fn len(&self) -> usize {
+        self.algorithms.len()
+    }
+
+    /// 注册表是否为空
+    pub fn is_empty(&self) -> bool {
+        self.algorithms.is_empty()
+    }
+}
+
+impl<T> Default for Registry<T> {
+    fn default() -> Self {
+        Registry::new()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Registry<T> {
+    type Item = &'a Algorithm<T>;
+    type IntoIter = std::slice::Iter<'a, Algorithm<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// 所有比较排序，适用于任意 `T: Ord`
+pub fn comparison_sorts<T: Ord>() -> Registry<T> {
+    let mut registry = Registry::new();
+
+    registry.register("bubble_sort", true, bubble_sort::bubble_sort_with_order);
+    registry.register(
+        "selection_sort",
+        false,
+        selection_sort::selection_sort_with_order,
+    );
+    registry.register(
+        "selection_sort_bidirectional",
+        false,
+        selection_sort::selection_sort_bidirectional_with_order,
+    );
+    registry.register(
+        "insertion_sort",
+        true,
+        insertion_sort::insertion_sort_with_order,
+    );
+    registry.register(
+        "insertion_sort_swap",
+        true,
+        insertion_sort::insertion_sort_swap_with_order,
+    );
+    registry.register(
+        "binary_insertion_sort",
+        true,
+        insertion_sort::binary_insertion_sort_with_order,
+    );
+    registry.register("shell_sort", false, shell_sort::shell_sort_with_order);
+    registry.register("merge_sort", true, merge_sort::merge_sort_with_order);
+    registry.register(
+        "merge_sort_iterative",
+        true,
+        merge_sort::merge_sort_iterative_with_order,
+    );
+    registry.register(
+        "merge_sort_ping_pong",
+        true,
+        merge_sort::merge_sort_ping_pong_with_order,
+    );
+    registry.register(
+        "merge_sort_in_place",
+        true,
+        merge_sort::merge_sort_in_place_with_order,
+    );
+    registry.register("tim_sort", true, tim_sort::tim_sort_with_order);
+    registry.register(
+        "block_merge_sort",
+        true,
+        block_merge_sort::block_merge_sort_with_order,
+    );
+    registry.register("quick_sort", false, quick_sort::quick_sort_with_order);
+    registry.register(
+        "quick_sort_hoare",
+        false,
+        quick_sort::quick_sort_hoare_with_order,
+    );
+    registry.register(
+        "quick_sort_3way",
+        false,
+        quick_sort::quick_sort_3way_with_order,
+    );
+    registry.register(
+        "quick_sort_block",
+        false,
+        quick_sort::quick_sort_block_with_order,
+    );
+    registry.register(
+        "quick_sort_dual_pivot",
+        false,
+        quick_sort::quick_sort_dual_pivot_with_order,
+    );
+    registry.register("intro_sort", false, intro_sort::intro_sort_with_order);
+    registry.register("pdq_sort", false, pdq_sort::pdq_sort_with_order);
+    registry.register("heap_sort", false, heap_sort::heap_sort_with_order);
+    registry.register(
+        "heap_sort_iterative",
+        false,
+        heap_sort::heap_sort_iterative_with_order,
+    );
+    registry.register(
+        "heap_sort_bottom_up",
+        false,
+        heap_sort::heap_sort_bottom_up_with_order,
+    );
+    registry.register("smooth_sort", false, smooth_sort::smooth_sort_with_order);
+
+    registry
+}
+
+/// 比较排序 + 并行比较排序，适用于可以跨线程移动的 `T: Ord + Send`
+pub fn parallel_sorts<T: Ord + Send>() -> Registry<T> {
+    let mut registry = comparison_sorts();
+    registry.register(
+        "merge_sort_parallel",
+        true,
+        merge_sort::merge_sort_parallel_with_order,
+    );
+    registry.register(
+        "quick_sort_parallel",
+        false,
+        quick_sort::quick_sort_parallel_with_order,
+    );
+    registry
+}
+
+/// 比较排序 + LSD/MSD 基数排序，适用于实现了 [`RadixKey`] 的 `T: Ord`
+pub fn radix_sorts<T: Ord + RadixKey>() -> Registry<T> {
+    let mut registry = comparison_sorts();
+    register_radix(&mut registry);
+    registry
+}
+
+/// 比较排序 + 并行比较排序 + 基数排序 + 并行 LSD 基数排序
+pub fn parallel_radix_sorts<T: Ord + RadixKey + Send + Sync>() -> Registry<T> {
+    let mut registry = parallel_sorts();
+    register_radix(&mut registry);
+    registry.register(
+        "radix_sort_parallel",
+        true,
+        radix_sort::radix_sort_parallel_with_order,
+    );
+    registry
+}
+
+fn register_radix<T: RadixKey>(registry: &mut Registry<T>) {
+    registry.register("radix_sort", true, radix_sort::radix_sort_with_order);
+    // MSD 版本是原地交换的美国国旗排序，不稳定
+    registry.register(
+        "radix_sort_msd",
+        false,
+        radix_sort::radix_sort_msd_with_order,
+    );
+}
+
+/// `usize` 可用的全部算法：比较排序 + 基数排序 + 计数排序
+pub fn usize_sorts() -> Registry<usize> {
+    let mut registry = radix_sorts();
+    registry.register(
+        "counting_sort",
+        true,
+        counting_sort::counting_sort_with_order,
+    );
+    registry
+}
+
+/// `i32` 可用的全部算法：比较排序 + 基数排序 + 稳定计数排序 + 整数桶排序
+pub fn i32_sorts() -> Registry<i32> {
+    let mut registry = radix_sorts();
+    registry.register(
+        "counting_sort_stable",
+        true,
+        counting_sort::counting_sort_stable_with_order,
+    );
+    registry.register(
+        "bucket_sort_int",
+        true,
+        bucket_sort::bucket_sort_int_with_order,
+    );
+    registry
+}
+
+/// `u32` 可用的全部算法：比较排序 + LSD/MSD 基数排序
+pub fn u32_sorts() -> Registry<u32> {
+    radix_sorts()
+}
+
+/// `f64` 可用的算法：`f64` 不满足 `Ord`，只有基数排序（按 `f64::total_cmp` 的顺序）
+/// 和 [0, 1) 区间的浮点桶排序
+pub fn f64_sorts() -> Registry<f64> {
+    let mut registry = Registry::new();
+    register_radix(&mut registry);
+    registry.register(
+        "bucket_sort_float",
+        true,
+        bucket_sort::bucket_sort_float_with_order,
+    );
+    registry
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{assert_each_once, random_vec, tracked_vec, Tracked};
+
+    fn assert_all_sort<T: Clone + std::fmt::Debug + PartialEq>(
+        registry: &Registry<T>,
+        input: &[T],
+        expected: &[T],
+    ) {
+        for algorithm in registry {
+            let mut arr = input.to_vec();
+            algorithm.sort(&mut arr);
+            assert_eq!(arr, expected, "{} 排序结果错误", algorithm.name());
+        }
+    }
+
+    #[test]
+    fn test_comparison_sorts() {
+        let registry = comparison_sorts::<i32>();
+        assert_all_sort(
+            &registry,
+            &[3, 1, 4, 1, 5, 9, 2, 6, 5, 3, 5],
+            &[1, 1, 2, 3, 3, 4, 5, 5, 5, 6, 9],
+        );
+    }
+
+    #[test]
+    fn test_parallel_sorts() {
+        let registry = parallel_sorts::<u64>();
+        assert_eq!(registry.len(), comparison_sorts::<u64>().len() + 2);
+        assert_all_sort(
+            &registry,
+            &[3, 1, 4, 1, 5, 9, 2, 6],
+            &[1, 1, 2, 3, 4, 5, 6, 9],
+        );
+
+        // 超过顺序排序阈值，真正使用多个线程
+        for name in ["merge_sort_parallel", "quick_sort_parallel"] {
+            let parallel = registry.get(name).unwrap();
+            let mut arr = random_vec(50_000, 1000, 52);
+            parallel.sort_with_order(&mut arr, Order::Descending);
+            assert!(
+                arr.windows(2).all(|w| w[0] >= w[1]),
+                "{} 排序结果错误",
+                name
+            );
+        }
+        assert!(registry.get("merge_sort_parallel").unwrap().is_stable());
+
+        let registry = parallel_radix_sorts::<u64>();
+        assert_eq!(registry.len(), radix_sorts::<u64>().len() + 3);
+        let radix = registry.get("radix_sort_parallel").unwrap();
+        assert!(radix.is_stable());
+        let mut arr = random_vec(50_000, u64::MAX, 53);
+        let mut expected = arr.clone();
+        expected.sort();
+        radix.sort(&mut arr);
+        assert_eq!(arr, expected);
+    }
+
+    #[test]
+    fn test_typed_registries() {
+        assert_all_sort(
+            &usize_sorts(),
+            &[4, 2, 2, 8, 3, 3, 1],
+            &[1, 2, 2, 3, 3, 4, 8],
+        );
+        assert_all_sort(
+            &i32_sorts(),
+            &[-5, 3, -1, 0, 3, -5, 2],
+            &[-5, -5, -1, 0, 2, 3, 3],
+        );
+        assert_all_sort(
+            &u32_sorts(),
+            &[170, 45, 75, 90, 802, 24, 2, 66],
+            &[2, 24, 45, 66, 75, 90, 170, 802],
+        );
+        assert_all_sort(
+            &f64_sorts(),
+            &[0.78, 0.17, 0.39, 0.26, 0.72],
+            &[0.17, 0.26, 0.39, 0.72, 0.78],
+        );
+    }
+
+    #[test]
+    fn test_lookup_by_name() {
+        let registry = u32_sorts();
+        assert_eq!(registry.len(), comparison_sorts::<u32>().len() + 2);
+        assert!(registry.names().any(|name| name == "radix_sort_msd"));
+
+        let merge = registry.get("merge_sort").unwrap();
+        assert_eq!(merge.name(), "merge_sort");
+        assert!(merge.is_stable());
+        assert!(!registry.get("quick_sort").unwrap().is_stable());
+        assert!(registry.get("no_such_sort").is_none());
+    }
+
+    #[test]
+    fn test_descending_keeps_stability() {
+        // 只按 key 比较，tag 记录原始顺序
+        #[derive(Clone, Debug, PartialEq, Eq)]
+        struct Item {
+            key: i32,
+            tag: char,
+        }
+
+        impl PartialOrd for Item {
+            fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl Ord for Item {
+            fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+                self.key.cmp(&other.key)
+            }
+        }
+
+        let input: Vec<Item> = [(1, 'a'), (3, 'b'), (1, 'c'), (2, 'd'), (3, 'e'), (2, 'f')]
+            .iter()
+            .map(|&(key, tag)| Item { key, tag })
+            .collect();
+
+        for algorithm in comparison_sorts::<Item>().iter() {
+            let mut arr = input.clone();
+            algorithm.sort_with_order(&mut arr, Order::Descending);
+            let keys: Vec<i32> = arr.iter().map(|item| item.key).collect();
+            assert_eq!(keys, vec![3, 3, 2, 2, 1, 1], "{}", algorithm.name());
+
+            if algorithm.is_stable() {
+                let tags: String = arr.iter().map(|item| item.tag).collect();
+                assert_eq!(tags, "bedfac", "{} 降序时不稳定", algorithm.name());
+            }
+        }
+    }
+
+    #[test]
+    fn test_typed_registries_descending() {
+        for algorithm in &i32_sorts() {
+            let mut arr = vec![-5, 3, -1, 0, 3, -5, 2];
+            algorithm.sort_with_order(&mut arr, Order::Descending);
+            assert_eq!(arr, vec![3, 3, 2, 0, -1, -5, -5], "{}", algorithm.name());
+        }
+        for algorithm in &u32_sorts() {
+            let mut arr = vec![170, 45, 75, 90, 802, 24, 2, 66];
+            algorithm.sort_with_order(&mut arr, Order::Descending);
+            assert_eq!(
+                arr,
+                vec![802, 170, 90, 75, 66, 45, 24, 2],
+                "{}",
+                algorithm.name()
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_duplicate_name() {
+        let mut registry = comparison_sorts::<i32>();
+        registry.register("heap_sort", false, heap_sort::heap_sort_with_order);
+    }
+
+    #[test]
+    fn test_non_clone_elements() {
+        let registry = comparison_sorts::<Tracked>();
+        let keys = random_vec(100, 10, 51);
+        for algorithm in &registry {
+            for order in [Order::Ascending, Order::Descending] {
+                let (mut arr, drops) = tracked_vec(&keys);
+                algorithm.sort_with_order(&mut arr, order);
+                assert!(
+                    arr.windows(2)
+                        .all(|w| order.apply(w[0].key.cmp(&w[1].key)).is_le()),
+                    "{} 排序结果错误",
+                    algorithm.name()
+                );
+                assert_each_once(arr, &drops);
+            }
+        }
+    }
+}
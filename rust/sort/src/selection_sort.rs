@@ -1,24 +1,28 @@
-/// 选择排序 (Selection Sort)
-///
-/// # 算法原理
-/// 选择排序是一种简单直观的排序算法。它的工作原理是：
-/// 1. 首先在未排序序列中找到最小（大）元素，存放到排序序列的起始位置
-/// 2. 然后，再从剩余未排序元素中继续寻找最小（大）元素
-/// 3. 放到已排序序列的末尾
-/// 4. 重复第二步，直到所有元素均排序完毕
-///
-/// # 时间复杂度
-/// - 最好情况: O(n²)
-/// - 最坏情况: O(n²)
-/// - 平均情况: O(n²)
-/// 注意：无论初始状态如何，选择排序的比较次数都是相同的
-///
-/// # 空间复杂度
-/// O(1) - 只需要常数级别的额外空间
-///
-/// # 稳定性
-/// 不稳定排序 - 相等元素的相对顺序可能会改变
-/// 例如：[5a, 8, 5b, 2] 排序后可能变成 [2, 5b, 5a, 8]
+//! 选择排序 (Selection Sort)
+//!
+//! # 算法原理
+//! 选择排序是一种简单直观的排序算法。它的工作原理是：
+//! 1. 首先在未排序序列中找到最小（大）元素，存放到排序序列的起始位置
+//! 2. 然后，再从剩余未排序元素中继续寻找最小（大）元素
+//! 3. 放到已排序序列的末尾
+//! 4. 重复第二步，直到所有元素均排序完毕
+//!
+//! # 时间复杂度
+//! - 最好情况: O(n²)
+//! - 最坏情况: O(n²)
+//! - 平均情况: O(n²)
+//!
+//! 注意：无论初始状态如何，选择排序的比较次数都是相同的
+//!
+//! # 空间复杂度
+//! O(1) - 只需要常数级别的额外空间
+//!
+//! # 稳定性
+//! 不稳定排序 - 相等元素的相对顺序可能会改变
+//! 例如：[5a, 8, 5b, 2] 排序后可能变成 [2, 5b, 5a, 8]
+
+use crate::Order;
+use std::cmp::Ordering;
 
 /// 选择排序函数
 ///
@@ -27,11 +31,28 @@
 ///
 /// # 示例
 /// ```
+/// use sort::selection_sort::selection_sort;
+///
 /// let mut arr = vec![64, 25, 12, 22, 11];
 /// selection_sort(&mut arr);
 /// assert_eq!(arr, vec![11, 12, 22, 25, 64]);
 /// ```
-fn selection_sort<T: Ord>(arr: &mut [T]) {
+pub fn selection_sort<T: Ord>(arr: &mut [T]) {
+    selection_sort_by(arr, T::cmp);
+}
+
+/// 按指定方向排序的选择排序
+pub fn selection_sort_with_order<T: Ord>(arr: &mut [T], order: Order) {
+    selection_sort_by(arr, |a, b| order.apply(a.cmp(b)));
+}
+
+/// 使用自定义比较函数的选择排序
+///
+/// `compare(a, b)` 返回 `Ordering::Less` 表示 `a` 应排在 `b` 之前。
+pub fn selection_sort_by<T, F>(arr: &mut [T], mut compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
     let n = arr.len();
 
     // 如果数组长度小于等于1，无需排序
@@ -48,7 +69,7 @@ fn selection_sort<T: Ord>(arr: &mut [T]) {
         // 内层循环：在未排序部分中找到最小元素的索引
         for j in (i + 1)..n {
             // 如果找到更小的元素，更新最小元素的索引
-            if arr[j] < arr[min_index] {
+            if compare(&arr[j], &arr[min_index]).is_lt() {
                 min_index = j;
             }
         }
@@ -61,10 +82,32 @@ fn selection_sort<T: Ord>(arr: &mut [T]) {
     }
 }
 
+/// 按键排序的选择排序，比较 `key(a)` 与 `key(b)`
+pub fn selection_sort_by_key<T, K, F>(arr: &mut [T], mut key: F)
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    selection_sort_by(arr, |a, b| key(a).cmp(&key(b)));
+}
+
 /// 双向选择排序（优化版本）
 /// 每次遍历同时找到最小值和最大值，分别放到两端
 /// 可以将遍历次数减少一半
-fn selection_sort_bidirectional<T: Ord>(arr: &mut [T]) {
+pub fn selection_sort_bidirectional<T: Ord>(arr: &mut [T]) {
+    selection_sort_bidirectional_by(arr, T::cmp);
+}
+
+/// 按指定方向排序的双向选择排序
+pub fn selection_sort_bidirectional_with_order<T: Ord>(arr: &mut [T], order: Order) {
+    selection_sort_bidirectional_by(arr, |a, b| order.apply(a.cmp(b)));
+}
+
+/// 使用自定义比较函数的双向选择排序
+pub fn selection_sort_bidirectional_by<T, F>(arr: &mut [T], mut compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
     let n = arr.len();
 
     if n <= 1 {
@@ -80,10 +123,10 @@ fn selection_sort_bidirectional<T: Ord>(arr: &mut [T]) {
 
         // 在未排序区间 [left, right] 中同时找最小值和最大值
         for i in left..=right {
-            if arr[i] < arr[min_index] {
+            if compare(&arr[i], &arr[min_index]).is_lt() {
                 min_index = i;
             }
-            if arr[i] > arr[max_index] {
+            if compare(&arr[i], &arr[max_index]).is_gt() {
                 max_index = i;
             }
         }
@@ -110,58 +153,13 @@ fn selection_sort_bidirectional<T: Ord>(arr: &mut [T]) {
     }
 }
 
-/// 主函数 - 演示选择排序的使用
-fn main() {
-    println!("=== 选择排序 (Selection Sort) 演示 ===\n");
-
-    // 示例1：整数数组排序
-    let mut numbers = vec![64, 25, 12, 22, 11];
-    println!("原始数组: {:?}", numbers);
-    selection_sort(&mut numbers);
-    println!("排序后:   {:?}", numbers);
-
-    println!();
-
-    // 示例2：使用双向选择排序
-    let mut numbers2 = vec![29, 10, 14, 37, 13, 8, 25];
-    println!("原始数组: {:?}", numbers2);
-    selection_sort_bidirectional(&mut numbers2);
-    println!("双向选择排序后: {:?}", numbers2);
-
-    println!();
-
-    // 示例3：带有重复元素的数组
-    let mut with_duplicates = vec![4, 2, 4, 1, 3, 2, 1];
-    println!("带重复元素: {:?}", with_duplicates);
-    selection_sort(&mut with_duplicates);
-    println!("排序后:     {:?}", with_duplicates);
-
-    println!();
-
-    // 示例4：字符排序
-    let mut chars = vec!['d', 'b', 'a', 'c', 'e'];
-    println!("字符数组: {:?}", chars);
-    selection_sort(&mut chars);
-    println!("排序后:   {:?}", chars);
-
-    println!();
-
-    // 示例5：边界情况
-    let mut empty: Vec<i32> = vec![];
-    let mut single = vec![42];
-    let mut two = vec![2, 1];
-
-    println!("空数组: {:?}", empty);
-    selection_sort(&mut empty);
-    println!("排序后: {:?}", empty);
-
-    println!("单元素: {:?}", single);
-    selection_sort(&mut single);
-    println!("排序后: {:?}", single);
-
-    println!("两元素: {:?}", two);
-    selection_sort(&mut two);
-    println!("排序后: {:?}", two);
+/// 按键排序的双向选择排序，比较 `key(a)` 与 `key(b)`
+pub fn selection_sort_bidirectional_by_key<T, K, F>(arr: &mut [T], mut key: F)
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    selection_sort_bidirectional_by(arr, |a, b| key(a).cmp(&key(b)));
 }
 
 #[cfg(test)]
@@ -216,4 +214,18 @@ mod tests {
         selection_sort(&mut arr);
         assert_eq!(arr, vec![1, 1, 2, 3, 3, 4, 5, 5, 5, 6, 9]);
     }
+
+    #[test]
+    fn test_selection_sort_by() {
+        let mut arr = vec![64, 25, 12, 22, 11];
+        selection_sort_by(&mut arr, |a, b| b.cmp(a));
+        assert_eq!(arr, vec![64, 25, 22, 12, 11]);
+    }
+
+    #[test]
+    fn test_bidirectional_by() {
+        let mut words = vec!["banana", "kiwi", "apple", "fig", "cherry"];
+        selection_sort_bidirectional_by(&mut words, |a, b| a.len().cmp(&b.len()).then(a.cmp(b)));
+        assert_eq!(words, vec!["fig", "kiwi", "apple", "banana", "cherry"]);
+    }
 }
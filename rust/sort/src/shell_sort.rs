@@ -0,0 +1,287 @@
+//! 希尔排序 (Shell Sort)
+//!
+//! # 算法原理
+//! 希尔排序是插入排序的改进版本，使用增量序列分组进行插入排序。
+//! 性能取决于增量序列，`shell_sort_with_gaps` 可以选择 [`GapSequence`] 中的各种序列。
+//!
+//! # 时间复杂度
+//! - 平均: O(n^1.3) ~ O(n^1.5)
+//! - 最坏: Shell 原始序列 O(n²)，Hibbard/Knuth O(n^1.5)，Sedgewick O(n^(4/3))，Pratt O(n log² n)
+//!
+//! # 空间复杂度: O(1)
+//! # 稳定性: 不稳定
+
+use crate::cached_key::sort_by_cached_key;
+use crate::insertion_sort::insert_tail_by;
+use crate::Order;
+use std::cmp::Ordering;
+
+/// 希尔排序（Shell 原始增量序列 n/2, n/4, ..., 1）
+pub fn shell_sort<T: Ord>(arr: &mut [T]) {
+    shell_sort_by(arr, T::cmp);
+}
+
+/// 按指定方向排序的希尔排序
+pub fn shell_sort_with_order<T: Ord>(arr: &mut [T], order: Order) {
+    shell_sort_by(arr, |a, b| order.apply(a.cmp(b)));
+}
+
+/// 使用自定义比较函数的希尔排序
+pub fn shell_sort_by<T, F>(arr: &mut [T], compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    shell_sort_with_gaps_by(arr, &GapSequence::Shell, compare);
+}
+
+/// 按键排序的希尔排序，比较 `key(a)` 与 `key(b)`
+///
+/// 每次比较都会重新计算键，键的计算代价较高时改用 [`shell_sort_by_cached_key`]。
+pub fn shell_sort_by_key<T, K, F>(arr: &mut [T], mut key: F)
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    shell_sort_by(arr, |a, b| key(a).cmp(&key(b)));
+}
+
+/// 缓存键的希尔排序：每个元素的键只计算一次，结果是稳定的
+pub fn shell_sort_by_cached_key<T, K, F>(arr: &mut [T], key: F)
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    sort_by_cached_key(arr, key, shell_sort);
+}
+
+/// 希尔排序的增量序列
+///
+/// 各序列的增量都从小于数组长度的最大值开始，依次递减到 1。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GapSequence {
+    /// Shell 原始序列：n/2, n/4, ..., 1，最坏 O(n²)
+    Shell,
+    /// Hibbard 序列：2^k - 1，即 1, 3, 7, 15, ...，最坏 O(n^1.5)
+    Hibbard,
+    /// Knuth 序列：(3^k - 1) / 2，即 1, 4, 13, 40, ...，最坏 O(n^1.5)
+    Knuth,
+    /// Sedgewick 序列：9·4^k - 9·2^k + 1 与 4^k - 3·2^k + 1 交替，即 1, 5, 19, 41, 109, ...，最坏 O(n^(4/3))
+    Sedgewick,
+    /// Tokuda 序列：h = ⌈(9^k - 4^k) / (5·4^(k-1))⌉，即 1, 4, 9, 20, 46, ...
+    Tokuda,
+    /// Ciura 序列：实验得出的 1, 4, 10, 23, 57, 132, 301, 701, 1750，
+    /// 之后按 h = ⌊2.25·h⌋ 扩展，实际中通常最快
+    Ciura,
+    /// Pratt 序列：所有 2^p·3^q，即 1, 2, 3, 4, 6, 8, 9, 12, ...，
+    /// 最坏 O(n log² n)，但增量个数多，实际较慢
+    Pratt,
+    /// 自定义序列：忽略 0 和不小于数组长度的增量，去重后从大到小使用，
+    /// 缺少 1 时在最后补上 1，保证结果有序
+    Custom(Vec<usize>),
+}
+
+/// Ciura 通过实验得到的增量
+const CIURA_GAPS: [usize; 9] = [1, 4, 10, 23, 57, 132, 301, 701, 1750];
+
+impl GapSequence {
+    /// 长度为 `n` 的数组实际使用的增量，从大到小排列，最后一个是 1
+    ///
+    /// `n` 不超过 1 时不需要排序，返回空序列。
+    pub fn gaps(&self, n: usize) -> Vec<usize> {
+        if n <= 1 {
+            return Vec::new();
+        }
+
+        let mut gaps = match self {
+            GapSequence::Shell => {
+                let mut gaps = Vec::new();
+                let mut gap = n / 2;
+                while gap > 0 {
+                    gaps.push(gap);
+                    gap /= 2;
+                }
+                gaps.reverse();
+                gaps
+            }
+            GapSequence::Hibbard => generate(n, 1, |h| h.checked_mul(2).map(|h| h + 1)),
+            GapSequence::Knuth => generate(n, 1, |h| h.checked_mul(3).map(|h| h + 1)),
+            GapSequence::Sedgewick => {
+                let mut gaps = vec![1];
+                for k in 1..u64::BITS {
+                    let gap = if k % 2 == 0 {
+                        9 * (1u128 << k) - 9 * (1u128 << (k / 2)) + 1
+                    } else {
+                        8 * (1u128 << k) - 6 * (1u128 << k.div_ceil(2)) + 1
+                    };
+                    if gap >= n as u128 {
+                        break;
+                    }
+                    gaps.push(gap as usize);
+                }
+                gaps
+            }
+            GapSequence::Tokuda => {
+                // 按 x = 2.25·x + 1 递推，增量是 x 向上取整
+                let mut gaps = Vec::new();
+                let mut x = 1.0f64;
+                while x.ceil() < n as f64 {
+                    gaps.push(x.ceil() as usize);
+                    x = 2.25 * x + 1.0;
+                }
+                gaps
+            }
+            GapSequence::Ciura => {
+                let mut gaps: Vec<usize> = CIURA_GAPS.iter().copied().filter(|&h| h < n).collect();
+                if gaps.len() == CIURA_GAPS.len() {
+                    // 9 / 4 = 2.25
+                    let last = gaps.pop().unwrap();
+                    gaps.extend(generate(n, last, |h| h.checked_mul(9).map(|h| h / 4)));
+                }
+                gaps
+            }
+            GapSequence::Pratt => {
+                let mut gaps = Vec::new();
+                let mut power_of_two = Some(1usize);
+                while let Some(p) = power_of_two.filter(|&p| p < n) {
+                    gaps.extend(generate(n, p, |h| h.checked_mul(3)));
+                    power_of_two = p.checked_mul(2);
+                }
+                gaps.sort_unstable();
+                gaps
+            }
+            GapSequence::Custom(gaps) => {
+                let mut gaps: Vec<usize> =
+                    gaps.iter().copied().filter(|&h| h > 0 && h < n).collect();
+                gaps.sort_unstable();
+                gaps.dedup();
+                if gaps.first() != Some(&1) {
+                    gaps.insert(0, 1);
+                }
+                gaps
+            }
+        };
+
+        gaps.reverse();
+        gaps
+    }
+}
+
+/// 从 `first` 开始按 `next` 递增，返回所有小于 `n` 的增量；`next` 返回 `None` 表示溢出
+fn generate(n: usize, first: usize, next: impl Fn(usize) -> Option<usize>) -> Vec<usize> {
+    let mut gaps = Vec::new();
+    let mut gap = Some(first);
+    while let Some(h) = gap.filter(|&h| h < n) {
+        gaps.push(h);
+        gap = next(h);
+    }
+    gaps
+}
+
+/// 使用指定增量序列的希尔排序
+pub fn shell_sort_with_gaps<T: Ord>(arr: &mut [T], gaps: &GapSequence) {
+    shell_sort_with_gaps_by(arr, gaps, T::cmp);
+}
+
+/// 使用指定增量序列和自定义比较函数的希尔排序
+pub fn shell_sort_with_gaps_by<T, F>(arr: &mut [T], gaps: &GapSequence, mut compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    for gap in gaps.gaps(arr.len()) {
+        // 对间隔为 gap 的每一组做插入排序，元素只移动不复制
+        for i in gap..arr.len() {
+            insert_tail_by(arr, i, gap, &mut compare);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{assert_each_once, random_vec, tracked_vec};
+
+    #[test]
+    fn test_shell_sort() {
+        let mut arr = vec![12, 34, 54, 2, 3];
+        shell_sort(&mut arr);
+        assert_eq!(arr, vec![2, 3, 12, 34, 54]);
+    }
+
+    #[test]
+    fn test_shell_sort_by() {
+        let mut arr = vec![12, 34, 54, 2, 3, 8, 9, 1, 5, 7];
+        shell_sort_by(&mut arr, |a, b| b.cmp(a));
+        assert_eq!(arr, vec![54, 34, 12, 9, 8, 7, 5, 3, 2, 1]);
+    }
+
+    #[test]
+    fn test_moves_without_clone() {
+        let (mut arr, drops) = tracked_vec(&random_vec(200, 50, 31));
+        shell_sort(&mut arr);
+        assert!(arr.windows(2).all(|w| w[0].key <= w[1].key));
+        assert_each_once(arr, &drops);
+    }
+
+    #[test]
+    fn test_gap_sequences() {
+        assert_eq!(GapSequence::Shell.gaps(100), vec![50, 25, 12, 6, 3, 1]);
+        assert_eq!(GapSequence::Hibbard.gaps(100), vec![63, 31, 15, 7, 3, 1]);
+        assert_eq!(GapSequence::Knuth.gaps(100), vec![40, 13, 4, 1]);
+        assert_eq!(GapSequence::Sedgewick.gaps(200), vec![109, 41, 19, 5, 1]);
+        assert_eq!(GapSequence::Tokuda.gaps(200), vec![103, 46, 20, 9, 4, 1]);
+        assert_eq!(GapSequence::Ciura.gaps(100), vec![57, 23, 10, 4, 1]);
+        assert_eq!(
+            GapSequence::Ciura.gaps(5000),
+            vec![3937, 1750, 701, 301, 132, 57, 23, 10, 4, 1]
+        );
+        assert_eq!(
+            GapSequence::Pratt.gaps(20),
+            vec![18, 16, 12, 9, 8, 6, 4, 3, 2, 1]
+        );
+        assert_eq!(
+            GapSequence::Custom(vec![0, 7, 3, 7, 200]).gaps(100),
+            vec![7, 3, 1]
+        );
+        assert_eq!(GapSequence::Knuth.gaps(1), vec![]);
+
+        // 数组很长时也不会溢出
+        for gaps in [
+            GapSequence::Hibbard,
+            GapSequence::Knuth,
+            GapSequence::Sedgewick,
+            GapSequence::Tokuda,
+            GapSequence::Ciura,
+            GapSequence::Pratt,
+        ] {
+            let seq = gaps.gaps(usize::MAX);
+            assert_eq!(seq.last(), Some(&1), "{:?}", gaps);
+            assert!(seq.windows(2).all(|w| w[0] > w[1]), "{:?}", gaps);
+        }
+    }
+
+    #[test]
+    fn test_shell_sort_with_gaps() {
+        let input = random_vec(3000, 1000, 32);
+        let mut expected = input.clone();
+        expected.sort();
+
+        for gaps in [
+            GapSequence::Shell,
+            GapSequence::Hibbard,
+            GapSequence::Knuth,
+            GapSequence::Sedgewick,
+            GapSequence::Tokuda,
+            GapSequence::Ciura,
+            GapSequence::Pratt,
+            GapSequence::Custom(vec![100, 10]),
+        ] {
+            let mut arr = input.clone();
+            shell_sort_with_gaps(&mut arr, &gaps);
+            assert_eq!(arr, expected, "{:?}", gaps);
+        }
+
+        let mut arr = vec![12, 34, 54, 2, 3, 8, 9, 1, 5, 7];
+        shell_sort_with_gaps_by(&mut arr, &GapSequence::Ciura, |a, b| b.cmp(a));
+        assert_eq!(arr, vec![54, 34, 12, 9, 8, 7, 5, 3, 2, 1]);
+    }
+}
@@ -0,0 +1,297 @@
+//! 平滑排序 (Smoothsort)
+//!
+//! # 算法原理
+//! 平滑排序是 Dijkstra 提出的堆排序变体，用一组 Leonardo 堆代替一个二叉堆：
+//! 1. Leonardo 数 L(0) = L(1) = 1，L(k) = L(k-1) + L(k-2) + 1；
+//!    k 阶 Leonardo 树有 L(k) 个节点，根的两个子树分别是 k-1 阶和 k-2 阶
+//! 2. 从左到右逐个加入元素，数组前缀总是由阶数递减的若干棵树组成；
+//!    最右边两棵树的阶数相邻时，新元素作为根把它们合并成一棵
+//! 3. 每棵树都满足最大堆性质，并且各棵树的根从左到右递增，最后一个元素就是最大值
+//! 4. 从右到左逐个取出最大值：去掉最后一棵树的根，两棵子树露出来，
+//!    把它们的根与左边各棵树的根比较，恢复根递增的性质
+//!
+//! 已经有序的输入中，新加入的元素总是不小于其他元素，调整时一两次比较就能停止，
+//! 所以接近有序时接近 O(n)，同时像堆排序一样只需要 O(1) 的额外空间。
+//!
+//! # 时间复杂度
+//! - 最好: O(n) - 已经有序
+//! - 平均/最坏: O(n log n)
+//!
+//! # 空间复杂度: O(1) - 原地排序，只用一个位掩码记录各棵树的阶数
+//! # 稳定性: 不稳定
+
+use crate::Order;
+use std::cmp::Ordering;
+
+/// Leonardo 数表，超出 `usize` 范围的项饱和为 `usize::MAX`
+const LEONARDO: [usize; 92] = leonardo_numbers();
+
+const fn leonardo_numbers() -> [usize; 92] {
+    let mut numbers = [1usize; 92];
+    let mut k = 2;
+    while k < numbers.len() {
+        numbers[k] = numbers[k - 1]
+            .saturating_add(numbers[k - 2])
+            .saturating_add(1);
+        k += 1;
+    }
+    numbers
+}
+
+/// 平滑排序主函数
+pub fn smooth_sort<T: Ord>(arr: &mut [T]) {
+    smooth_sort_by(arr, T::cmp);
+}
+
+/// 按指定方向排序的平滑排序
+pub fn smooth_sort_with_order<T: Ord>(arr: &mut [T], order: Order) {
+    smooth_sort_by(arr, |a, b| order.apply(a.cmp(b)));
+}
+
+/// 使用自定义比较函数的平滑排序
+pub fn smooth_sort_by<T, F>(arr: &mut [T], mut compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let n = arr.len();
+    if n <= 1 {
+        return;
+    }
+
+    // 第 k 位为 1 表示有一棵 k 阶树；阶数越小的树越靠右
+    let mut orders: u128 = 0;
+
+    // 第一步：逐个加入元素，建立 Leonardo 堆
+    for i in 0..n {
+        let smallest = orders.trailing_zeros();
+        let order = if orders != 0 && orders & (1 << (smallest + 1)) != 0 {
+            // 最右边两棵树的阶数相邻，以新元素为根合并
+            orders &= !(3 << smallest);
+            smallest + 2
+        } else if smallest == 1 {
+            0
+        } else {
+            1
+        };
+        orders |= 1 << order;
+
+        // 这棵树之后还会被合并时，只需要保证它本身是堆；
+        // 否则它的根已经是最终的根之一，还要与左边的根比较
+        let remaining = n - 1 - i;
+        let merged_with_left = orders & (1 << (order + 1)) != 0 && remaining >= 1;
+        let merged_with_right = order >= 1 && remaining > LEONARDO[order as usize - 1];
+        if merged_with_left || merged_with_right {
+            sift_down(arr, i, order, &mut compare);
+        } else {
+            rectify(arr, i, order, orders, false, &mut compare);
+        }
+    }
+
+    // 第二步：从右到左逐个取出最大值，也就是最后一棵树的根
+    for i in (1..n).rev() {
+        let order = orders.trailing_zeros();
+        orders &= !(1 << order);
+
+        // 0 阶和 1 阶的树只有根，去掉即可；更大的树露出两棵子树
+        if order >= 2 {
+            let right = i - 1;
+            let left = right - LEONARDO[order as usize - 2];
+            orders |= 3 << (order - 2);
+
+            // 两棵子树本身都是堆，只需要恢复根递增的性质
+            rectify(arr, left, order - 1, orders, true, &mut compare);
+            rectify(arr, right, order - 2, orders, true, &mut compare);
+        }
+    }
+}
+
+/// 按键排序的平滑排序，比较 `key(a)` 与 `key(b)`
+pub fn smooth_sort_by_key<T, K, F>(arr: &mut [T], mut key: F)
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    smooth_sort_by(arr, |a, b| key(a).cmp(&key(b)));
+}
+
+/// 在以 `root` 为根的 `order` 阶树中下沉根元素
+///
+/// 右子树的根在 `root - 1`，是 `order - 2` 阶；左子树的根在它左边 L(order-2) 处，是 `order - 1` 阶。
+fn sift_down<T, F>(arr: &mut [T], mut root: usize, mut order: u32, compare: &mut F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    while order >= 2 {
+        let right = root - 1;
+        let left = right - LEONARDO[order as usize - 2];
+
+        let (child, child_order) = if compare(&arr[left], &arr[right]).is_gt() {
+            (left, order - 1)
+        } else {
+            (right, order - 2)
+        };
+        if !compare(&arr[child], &arr[root]).is_gt() {
+            break;
+        }
+
+        arr.swap(root, child);
+        root = child;
+        order = child_order;
+    }
+}
+
+/// 把以 `root` 为根的 `order` 阶树的根与左边各棵树的根比较，使各棵树的根从左到右递增
+///
+/// `orders` 中高于 `order` 的位描述了左边的树。`is_heap` 表示这棵树已经满足堆性质，
+/// 根不小于两个子节点；否则左边的根还要大于两个子节点才能换过来，最后再下沉。
+fn rectify<T, F>(
+    arr: &mut [T],
+    mut root: usize,
+    mut order: u32,
+    orders: u128,
+    mut is_heap: bool,
+    compare: &mut F,
+) where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    loop {
+        // 左边相邻的树是阶数比当前更高的最小的那一棵
+        let left_trees = orders & !((2 << order) - 1);
+        if left_trees == 0 {
+            break;
+        }
+        let stepson = root - LEONARDO[order as usize];
+
+        if !compare(&arr[stepson], &arr[root]).is_gt() {
+            break;
+        }
+        if !is_heap && order >= 2 {
+            let right = root - 1;
+            let left = right - LEONARDO[order as usize - 2];
+            if !compare(&arr[stepson], &arr[left]).is_gt()
+                || !compare(&arr[stepson], &arr[right]).is_gt()
+            {
+                break;
+            }
+        }
+
+        // 换过来的元素不小于当前树的所有节点；换过去的元素可能破坏左边那棵树的堆性质
+        arr.swap(stepson, root);
+        root = stepson;
+        order = left_trees.trailing_zeros();
+        is_heap = false;
+    }
+
+    if !is_heap {
+        sift_down(arr, root, order, compare);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{assert_each_once, random_vec, tracked_vec, Tracked};
+    use std::panic::{self, AssertUnwindSafe};
+
+    /// 排序并返回比较次数
+    fn count_comparisons(arr: &mut [u64]) -> usize {
+        let mut calls = 0;
+        smooth_sort_by(arr, |a, b| {
+            calls += 1;
+            a.cmp(b)
+        });
+        calls
+    }
+
+    #[test]
+    fn test_smooth_sort() {
+        let mut arr = vec![12, 11, 13, 5, 6, 7];
+        smooth_sort(&mut arr);
+        assert_eq!(arr, vec![5, 6, 7, 11, 12, 13]);
+
+        let mut arr: Vec<i32> = vec![];
+        smooth_sort(&mut arr);
+        assert_eq!(arr, vec![]);
+
+        for len in 1..200 {
+            let input = random_vec(len, 50, len as u64 + 81);
+            let mut expected = input.clone();
+            expected.sort();
+
+            let mut arr = input.clone();
+            smooth_sort(&mut arr);
+            assert_eq!(arr, expected, "len = {}", len);
+
+            let mut arr = input;
+            smooth_sort_with_order(&mut arr, Order::Descending);
+            expected.reverse();
+            assert_eq!(arr, expected, "len = {}", len);
+        }
+
+        let input = random_vec(20_000, 1_000_000, 82);
+        let mut expected = input.clone();
+        expected.sort();
+        let mut arr = input;
+        smooth_sort(&mut arr);
+        assert_eq!(arr, expected);
+    }
+
+    #[test]
+    fn test_leonardo_numbers() {
+        assert_eq!(LEONARDO[..10], [1, 1, 3, 5, 9, 15, 25, 41, 67, 109]);
+        // 最大的一阶足以容纳任意长度的数组
+        assert!(LEONARDO[LEONARDO.len() - 1] > usize::MAX / 2);
+    }
+
+    #[test]
+    fn test_adaptive() {
+        // 有序输入只需要线性次数的比较，与数组长度成正比
+        let n = 100_000;
+        let mut arr: Vec<u64> = (0..n).collect();
+        let sorted = count_comparisons(&mut arr);
+        assert!(sorted < 3 * n as usize, "有序输入比较 {} 次", sorted);
+
+        // 接近有序（少量相邻元素交换）时也远少于随机输入
+        let mut arr: Vec<u64> = (0..n).collect();
+        for i in (0..arr.len() - 1).step_by(100) {
+            arr.swap(i, i + 1);
+        }
+        let nearly = count_comparisons(&mut arr);
+        assert!(arr.windows(2).all(|w| w[0] <= w[1]));
+
+        let mut arr = random_vec(n as usize, 1_000_000, 83);
+        let random = count_comparisons(&mut arr);
+        assert!(
+            nearly * 3 < random,
+            "接近有序 {} 次，随机 {} 次",
+            nearly,
+            random
+        );
+    }
+
+    #[test]
+    fn test_moves_without_clone() {
+        let (mut arr, drops) = tracked_vec(&random_vec(500, 20, 84));
+        smooth_sort(&mut arr);
+        assert!(arr.windows(2).all(|w| w[0].key <= w[1].key));
+        assert_each_once(arr, &drops);
+    }
+
+    #[test]
+    fn test_panic_in_compare_keeps_every_element() {
+        let keys = random_vec(500, 100, 85);
+        for limit in [1, 100, 1000, 3000] {
+            let (mut arr, drops) = tracked_vec(&keys);
+            let mut calls = 0;
+            let result = panic::catch_unwind(AssertUnwindSafe(|| {
+                smooth_sort_by(&mut arr, |a: &Tracked, b: &Tracked| {
+                    calls += 1;
+                    assert!(calls < limit, "比较函数 panic");
+                    a.cmp(b)
+                })
+            }));
+            assert!(result.is_err());
+            assert_each_once(arr, &drops);
+        }
+    }
+}
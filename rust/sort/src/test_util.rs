@@ -0,0 +1,95 @@
+//! 测试用的辅助函数
+
+use std::cell::RefCell;
+use std::cmp::Ordering;
+use std::rc::Rc;
+
+/// 简单的 xorshift 伪随机数生成器，保证测试数据可复现
+pub(crate) struct XorShift(u64);
+
+impl XorShift {
+    pub(crate) fn new(seed: u64) -> Self {
+        XorShift(seed.max(1))
+    }
+
+    pub(crate) fn next_u64(&mut self) -> u64 {
+        let mut x = self.0;
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        self.0 = x;
+        x
+    }
+}
+
+/// 长度为 `len`、取值在 `[0, bound)` 之间的随机数组
+pub(crate) fn random_vec(len: usize, bound: u64, seed: u64) -> Vec<u64> {
+    let mut rng = XorShift::new(seed);
+    (0..len).map(|_| rng.next_u64() % bound).collect()
+}
+
+/// 不能 `Clone` 的元素，析构时记录自己的 `id`
+///
+/// 用来检查只移动元素的排序没有复制、丢失或重复释放任何元素。只按 `key` 比较。
+#[derive(Debug)]
+pub(crate) struct Tracked {
+    pub(crate) key: u64,
+    pub(crate) id: usize,
+    drops: Rc<RefCell<Vec<usize>>>,
+}
+
+impl PartialEq for Tracked {
+    fn eq(&self, other: &Self) -> bool {
+        self.key == other.key
+    }
+}
+
+impl Eq for Tracked {}
+
+impl PartialOrd for Tracked {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Tracked {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.key.cmp(&other.key)
+    }
+}
+
+impl Drop for Tracked {
+    fn drop(&mut self) {
+        self.drops.borrow_mut().push(self.id);
+    }
+}
+
+/// 按 `keys` 生成 `Tracked` 数组，`id` 依次为 0, 1, 2, ...，同时返回析构记录
+pub(crate) fn tracked_vec(keys: &[u64]) -> (Vec<Tracked>, Rc<RefCell<Vec<usize>>>) {
+    let drops = Rc::new(RefCell::new(Vec::new()));
+    let arr = keys
+        .iter()
+        .enumerate()
+        .map(|(id, &key)| Tracked {
+            key,
+            id,
+            drops: Rc::clone(&drops),
+        })
+        .collect();
+    (arr, drops)
+}
+
+/// 检查排序后的 `arr` 恰好包含每个 `id` 一次，然后释放它，检查每个元素恰好析构一次
+pub(crate) fn assert_each_once(arr: Vec<Tracked>, drops: &Rc<RefCell<Vec<usize>>>) {
+    let n = arr.len();
+    assert!(drops.borrow().is_empty(), "排序过程中释放了元素");
+
+    let mut ids: Vec<usize> = arr.iter().map(|t| t.id).collect();
+    ids.sort_unstable();
+    assert_eq!(ids, (0..n).collect::<Vec<_>>(), "元素被复制或丢失");
+
+    drop(arr);
+    let mut dropped = drops.borrow().clone();
+    dropped.sort_unstable();
+    assert_eq!(dropped, (0..n).collect::<Vec<_>>(), "元素没有恰好释放一次");
+}
@@ -0,0 +1,696 @@
+//! Timsort（自然归并排序）
+//!
+//! # 算法原理
+//! 实际数据往往由若干段已经有序的数据拼接而成，Timsort 利用这些已有的顺序：
+//! 1. 从左到右找出自然有序段（run）：不下降的段直接使用，严格下降的段原地反转
+//!    （严格下降才反转，相等元素的顺序不会被打乱）
+//! 2. 较短的有序段用二分插入排序扩展到最小长度 `min_run`（32 ~ 64）
+//! 3. 有序段压入栈中，按 Powersort 的合并策略决定何时合并相邻的两段：
+//!    为每个相邻的边界计算它在理想归并树中的深度，新边界更浅时先合并栈顶更深的边界，
+//!    使整体合并代价接近最优（CPython 3.11 起的 `list.sort` 也采用这一策略）
+//! 4. 合并时先用飞奔（galloping）跳过已经在正确位置的前缀和后缀，
+//!    某一侧连续胜出多次后改用指数搜索成块移动元素
+//!
+//! # 时间复杂度
+//! - 最好: O(n) - 已经有序或逆序，只有一个有序段
+//! - 平均/最坏: O(n log n)
+//!
+//! # 空间复杂度: O(n) - 合并时较短的一段需要移到缓冲区
+//! # 稳定性: 稳定排序
+
+use crate::insertion_sort::binary_insertion_sort_from_by;
+use crate::merge_sort::MergeHole;
+use crate::Order;
+use std::cmp::Ordering;
+use std::mem::{self, MaybeUninit};
+use std::ptr;
+use std::slice;
+
+/// 短于该长度的数组直接用二分插入排序
+const MIN_MERGE: usize = 64;
+
+/// 进入飞奔模式所需的连续胜出次数的初始值
+const MIN_GALLOP: usize = 7;
+
+/// Timsort 主函数
+pub fn tim_sort<T: Ord>(arr: &mut [T]) {
+    tim_sort_by(arr, T::cmp);
+}
+
+/// 按指定方向排序的 Timsort，降序时依然稳定
+pub fn tim_sort_with_order<T: Ord>(arr: &mut [T], order: Order) {
+    tim_sort_by(arr, |a, b| order.apply(a.cmp(b)));
+}
+
+/// 使用自定义比较函数的 Timsort
+pub fn tim_sort_by<T, F>(arr: &mut [T], mut compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let n = arr.len();
+    if n < 2 {
+        return;
+    }
+
+    if n < MIN_MERGE {
+        let run = count_run_and_make_ascending(arr, &mut compare);
+        binary_insertion_sort_from_by(arr, run, &mut compare);
+        return;
+    }
+
+    let min_run = min_run_length(n);
+    let scale = merge_tree_scale(n);
+    let mut buf = Vec::with_capacity(n / 2);
+    let mut merger = Merger {
+        buf: buf.spare_capacity_mut(),
+        min_gallop: MIN_GALLOP,
+    };
+    let mut runs: Vec<Run> = Vec::new();
+
+    let mut start = 0;
+    while start < n {
+        // 找到下一个自然有序段，太短时用二分插入排序扩展
+        let mut len = count_run_and_make_ascending(&mut arr[start..], &mut compare);
+        if len < min_run {
+            let end = (start + min_run).min(n);
+            binary_insertion_sort_from_by(&mut arr[start..end], len, &mut compare);
+            len = end - start;
+        }
+
+        // 新边界比栈顶的边界更浅时，先合并栈顶
+        let power = match runs.last() {
+            Some(top) => merge_tree_depth(top.start, start, start + len, scale),
+            None => 0,
+        };
+        while runs.len() >= 2 && runs[runs.len() - 1].power > power {
+            merger.merge_top(arr, &mut runs, &mut compare);
+        }
+
+        runs.push(Run { start, len, power });
+        start += len;
+    }
+
+    while runs.len() >= 2 {
+        merger.merge_top(arr, &mut runs, &mut compare);
+    }
+}
+
+/// 按键排序的 Timsort，比较 `key(a)` 与 `key(b)`
+pub fn tim_sort_by_key<T, K, F>(arr: &mut [T], mut key: F)
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    tim_sort_by(arr, |a, b| key(a).cmp(&key(b)));
+}
+
+/// 栈中的一个有序段，`power` 是它与前一段之间的边界在归并树中的深度
+struct Run {
+    start: usize,
+    len: usize,
+    power: u32,
+}
+
+/// 返回从开头开始的有序段长度；严格下降的段会被原地反转成升序
+fn count_run_and_make_ascending<T, F>(arr: &mut [T], compare: &mut F) -> usize
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let n = arr.len();
+    if n < 2 {
+        return n;
+    }
+
+    let mut end = 2;
+    if compare(&arr[1], &arr[0]).is_lt() {
+        while end < n && compare(&arr[end], &arr[end - 1]).is_lt() {
+            end += 1;
+        }
+        arr[..end].reverse();
+    } else {
+        while end < n && !compare(&arr[end], &arr[end - 1]).is_lt() {
+            end += 1;
+        }
+    }
+    end
+}
+
+/// 最小有序段长度：取 n 的最高 6 位，低位不全为 0 时再加 1，结果在 32 ~ 64 之间，
+/// 使 n / min_run 恰好是或略小于 2 的幂，合并时两侧长度接近
+fn min_run_length(mut n: usize) -> usize {
+    let mut r = 0;
+    while n >= MIN_MERGE {
+        r |= n & 1;
+        n >>= 1;
+    }
+    n + r
+}
+
+/// 把下标映射到 [0, 2^62) 的缩放因子
+fn merge_tree_scale(n: usize) -> u64 {
+    (1u64 << 62).div_ceil(n as u64)
+}
+
+/// Powersort 中相邻两段 `[left, mid)` 和 `[mid, right)` 之间边界的深度
+///
+/// 两段中点按 `scale` 缩放后，二进制表示的公共前缀越长，边界在理想归并树中越深。
+fn merge_tree_depth(left: usize, mid: usize, right: usize, scale: u64) -> u32 {
+    let x = left as u64 + mid as u64;
+    let y = mid as u64 + right as u64;
+    ((scale * x) ^ (scale * y)).leading_zeros()
+}
+
+/// 指数搜索：`pred` 在 `arr` 上先全为 `true` 再全为 `false`，返回 `true` 的个数
+///
+/// 从左端（或右端）开始按 1, 3, 7, 15, ... 的间隔试探，再在最后一个区间里二分查找，
+/// 答案离起点越近越快。
+fn gallop<T>(arr: &[T], mut pred: impl FnMut(&T) -> bool, from_right: bool) -> usize {
+    let n = arr.len();
+    let mut ofs = 1;
+    let (lo, hi);
+
+    if from_right {
+        // arr[prev..] 都为 false
+        let mut prev = n;
+        loop {
+            if ofs > n {
+                (lo, hi) = (0, prev);
+                break;
+            }
+            if pred(&arr[n - ofs]) {
+                (lo, hi) = (n - ofs + 1, prev);
+                break;
+            }
+            prev = n - ofs;
+            ofs = ofs * 2 + 1;
+        }
+    } else {
+        // arr[..prev] 都为 true
+        let mut prev = 0;
+        loop {
+            if ofs > n {
+                (lo, hi) = (prev, n);
+                break;
+            }
+            if !pred(&arr[ofs - 1]) {
+                (lo, hi) = (prev, ofs - 1);
+                break;
+            }
+            prev = ofs;
+            ofs = ofs * 2 + 1;
+        }
+    }
+
+    lo + arr[lo..hi].partition_point(pred)
+}
+
+/// 合并用的缓冲区和飞奔阈值，阈值在多次合并之间共享
+struct Merger<'a, T> {
+    buf: &'a mut [MaybeUninit<T>],
+    min_gallop: usize,
+}
+
+impl<T> Merger<'_, T> {
+    /// 合并栈顶的两个有序段
+    fn merge_top<F>(&mut self, arr: &mut [T], runs: &mut Vec<Run>, compare: &mut F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let right = runs.pop().unwrap();
+        let left = runs.last_mut().unwrap();
+        self.merge_runs(
+            &mut arr[left.start..right.start + right.len],
+            left.len,
+            compare,
+        );
+        left.len += right.len;
+    }
+
+    /// 合并相邻的有序段 `arr[..mid]` 和 `arr[mid..]`
+    fn merge_runs<F>(&mut self, arr: &mut [T], mid: usize, compare: &mut F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        // 零大小类型的元素无法区分，合并不改变任何内容；
+        // merge_lo 和 merge_hi 靠指针的差计算长度，元素大小为 0 时不成立
+        if mem::size_of::<T>() == 0 {
+            return;
+        }
+
+        // 左段中不大于右段第一个元素的前缀已经在正确位置
+        let (left, right) = arr.split_at(mid);
+        let skip = gallop(left, |x| !compare(&right[0], x).is_lt(), false);
+        if skip == mid {
+            return;
+        }
+        let arr = &mut arr[skip..];
+        let mid = mid - skip;
+
+        // 右段中不小于左段最后一个元素的后缀已经在正确位置
+        let (left, right) = arr.split_at(mid);
+        let right_len = gallop(right, |x| compare(x, &left[mid - 1]).is_lt(), true);
+        if right_len == 0 {
+            return;
+        }
+        let arr = &mut arr[..mid + right_len];
+
+        // 把较短的一段移到缓冲区
+        if mid <= right_len {
+            self.merge_lo(arr, mid, compare);
+        } else {
+            self.merge_hi(arr, mid, compare);
+        }
+    }
+
+    /// 左段较短：左段移到缓冲区，从前往后合并
+    ///
+    /// 调用前已经确认右段第一个元素小于左段第一个元素，左段最后一个元素大于右段所有元素。
+    fn merge_lo<F>(&mut self, arr: &mut [T], mid: usize, compare: &mut F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let len = arr.len();
+        let base = arr.as_mut_ptr();
+        let buf = self.buf[..mid].as_mut_ptr() as *mut T;
+
+        // SAFETY: 缓冲区至少能容纳 n / 2 个元素，而较短的一段不超过 n / 2。
+        // 合并过程中始终满足 `hole.dest + (hole.end - hole.start) == right`：
+        // `arr` 中 `[hole.dest, right)` 正好能放下缓冲区中剩余的左段元素，
+        // 右段剩余的元素已经在正确位置。`hole` 析构时（包括 `compare` panic 时）
+        // 把缓冲区中剩余的元素移回 `arr`，每个元素始终恰好出现一次。
+        unsafe {
+            ptr::copy_nonoverlapping(base, buf, mid);
+            let mut hole = MergeHole {
+                start: buf,
+                end: buf.add(mid),
+                dest: base,
+            };
+            let mut right = base.add(mid);
+            let right_end = base.add(len);
+
+            // 右段第一个元素最小，直接移动
+            ptr::copy_nonoverlapping(right, hole.dest, 1);
+            right = right.add(1);
+            hole.dest = hole.dest.add(1);
+            if right == right_end {
+                return;
+            }
+
+            'outer: loop {
+                let mut left_wins = 0;
+                let mut right_wins = 0;
+
+                // 逐个比较，直到某一侧连续胜出 min_gallop 次
+                loop {
+                    // 相等时优先取左侧元素，保证稳定性
+                    if compare(&*right, &*hole.start).is_lt() {
+                        ptr::copy_nonoverlapping(right, hole.dest, 1);
+                        right = right.add(1);
+                        hole.dest = hole.dest.add(1);
+                        right_wins += 1;
+                        left_wins = 0;
+                        if right == right_end {
+                            break 'outer;
+                        }
+                    } else {
+                        ptr::copy_nonoverlapping(hole.start, hole.dest, 1);
+                        hole.start = hole.start.add(1);
+                        hole.dest = hole.dest.add(1);
+                        left_wins += 1;
+                        right_wins = 0;
+                        if hole.start == hole.end {
+                            break 'outer;
+                        }
+                    }
+                    if left_wins.max(right_wins) >= self.min_gallop {
+                        break;
+                    }
+                }
+
+                // 飞奔模式：用指数搜索找出可以整块移动的元素
+                loop {
+                    // 左段中不大于右段当前元素的部分
+                    let left_len = hole.end.offset_from(hole.start) as usize;
+                    let left_run = slice::from_raw_parts(hole.start, left_len);
+                    let left_count = gallop(left_run, |x| !compare(&*right, x).is_lt(), false);
+                    ptr::copy_nonoverlapping(hole.start, hole.dest, left_count);
+                    hole.start = hole.start.add(left_count);
+                    hole.dest = hole.dest.add(left_count);
+                    if hole.start == hole.end {
+                        break 'outer;
+                    }
+
+                    // 此时右段当前元素小于左段当前元素
+                    ptr::copy_nonoverlapping(right, hole.dest, 1);
+                    right = right.add(1);
+                    hole.dest = hole.dest.add(1);
+                    if right == right_end {
+                        break 'outer;
+                    }
+
+                    // 右段中小于左段当前元素的部分，源和目标可能重叠
+                    let right_len = right_end.offset_from(right) as usize;
+                    let right_run = slice::from_raw_parts(right, right_len);
+                    let right_count =
+                        gallop(right_run, |x| compare(x, &*hole.start).is_lt(), false);
+                    ptr::copy(right, hole.dest, right_count);
+                    right = right.add(right_count);
+                    hole.dest = hole.dest.add(right_count);
+                    if right == right_end {
+                        break 'outer;
+                    }
+
+                    // 此时左段当前元素不大于右段当前元素
+                    ptr::copy_nonoverlapping(hole.start, hole.dest, 1);
+                    hole.start = hole.start.add(1);
+                    hole.dest = hole.dest.add(1);
+                    if hole.start == hole.end {
+                        break 'outer;
+                    }
+
+                    // 飞奔有效时降低阈值，更容易再次进入飞奔模式
+                    self.min_gallop = self.min_gallop.saturating_sub(1).max(1);
+                    if left_count < MIN_GALLOP && right_count < MIN_GALLOP {
+                        break;
+                    }
+                }
+                // 飞奔不再有效，提高阈值
+                self.min_gallop += 1;
+            }
+            // `hole` 在这里析构，把缓冲区中剩余的左段元素移回 `arr`
+        }
+    }
+
+    /// 右段较短：右段移到缓冲区，从后往前合并
+    ///
+    /// 调用前已经确认右段第一个元素小于左段第一个元素，左段最后一个元素大于右段所有元素。
+    fn merge_hi<F>(&mut self, arr: &mut [T], mid: usize, compare: &mut F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let len = arr.len();
+        let right_len = len - mid;
+        let base = arr.as_mut_ptr();
+        let buf = self.buf[..right_len].as_mut_ptr() as *mut T;
+
+        // SAFETY: 与 `merge_lo` 相同，只是方向相反。`hole.dest` 是左段剩余部分的末尾，
+        // 始终满足 `hole.dest + (hole.end - hole.start) == out`：`arr` 中 `[hole.dest, out)`
+        // 正好能放下缓冲区中剩余的右段元素，`hole` 析构时把它们移回。
+        unsafe {
+            ptr::copy_nonoverlapping(base.add(mid), buf, right_len);
+            let mut hole = MergeHole {
+                start: buf,
+                end: buf.add(right_len),
+                dest: base.add(mid),
+            };
+            let mut out = base.add(len);
+
+            // 左段最后一个元素最大，直接移动
+            out = out.sub(1);
+            hole.dest = hole.dest.sub(1);
+            ptr::copy_nonoverlapping(hole.dest, out, 1);
+            if hole.dest == base {
+                return;
+            }
+
+            'outer: loop {
+                let mut left_wins = 0;
+                let mut right_wins = 0;
+
+                loop {
+                    let left_last = hole.dest.sub(1);
+                    let right_last = hole.end.sub(1);
+                    // 相等时优先把右侧元素放到后面，保证稳定性
+                    if compare(&*right_last, &*left_last).is_lt() {
+                        out = out.sub(1);
+                        hole.dest = left_last;
+                        ptr::copy_nonoverlapping(left_last, out, 1);
+                        left_wins += 1;
+                        right_wins = 0;
+                        if hole.dest == base {
+                            break 'outer;
+                        }
+                    } else {
+                        out = out.sub(1);
+                        hole.end = right_last;
+                        ptr::copy_nonoverlapping(right_last, out, 1);
+                        right_wins += 1;
+                        left_wins = 0;
+                        if hole.start == hole.end {
+                            break 'outer;
+                        }
+                    }
+                    if left_wins.max(right_wins) >= self.min_gallop {
+                        break;
+                    }
+                }
+
+                loop {
+                    // 左段中大于右段最后一个元素的部分，源和目标可能重叠
+                    let left_len = hole.dest.offset_from(base) as usize;
+                    let left_run = slice::from_raw_parts(base, left_len);
+                    let right_last = hole.end.sub(1);
+                    let keep = gallop(left_run, |x| !compare(&*right_last, x).is_lt(), true);
+                    let left_count = left_len - keep;
+                    out = out.sub(left_count);
+                    hole.dest = hole.dest.sub(left_count);
+                    ptr::copy(hole.dest, out, left_count);
+                    if hole.dest == base {
+                        break 'outer;
+                    }
+
+                    // 此时右段最后一个元素不小于左段最后一个元素
+                    out = out.sub(1);
+                    hole.end = hole.end.sub(1);
+                    ptr::copy_nonoverlapping(hole.end, out, 1);
+                    if hole.start == hole.end {
+                        break 'outer;
+                    }
+
+                    // 右段中不小于左段最后一个元素的部分
+                    let right_len = hole.end.offset_from(hole.start) as usize;
+                    let right_run = slice::from_raw_parts(hole.start, right_len);
+                    let left_last = hole.dest.sub(1);
+                    let keep = gallop(right_run, |x| compare(x, &*left_last).is_lt(), true);
+                    let right_count = right_len - keep;
+                    out = out.sub(right_count);
+                    hole.end = hole.end.sub(right_count);
+                    ptr::copy_nonoverlapping(hole.end, out, right_count);
+                    if hole.start == hole.end {
+                        break 'outer;
+                    }
+
+                    // 此时左段最后一个元素大于右段最后一个元素
+                    out = out.sub(1);
+                    hole.dest = hole.dest.sub(1);
+                    ptr::copy_nonoverlapping(hole.dest, out, 1);
+                    if hole.dest == base {
+                        break 'outer;
+                    }
+
+                    self.min_gallop = self.min_gallop.saturating_sub(1).max(1);
+                    if left_count < MIN_GALLOP && right_count < MIN_GALLOP {
+                        break;
+                    }
+                }
+                self.min_gallop += 1;
+            }
+            // `hole` 在这里析构，把缓冲区中剩余的右段元素移回 `arr`
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::merge_sort::merge_sort_by;
+    use crate::test_util::{assert_each_once, random_vec, tracked_vec, Tracked};
+    use std::panic::{self, AssertUnwindSafe};
+
+    /// 排序并返回比较次数
+    fn count_comparisons(arr: &mut [u64]) -> usize {
+        let mut calls = 0;
+        tim_sort_by(arr, |a, b| {
+            calls += 1;
+            a.cmp(b)
+        });
+        calls
+    }
+
+    /// 把 `chunks` 个有序段拼接起来，每段长度为 `len`
+    fn sorted_chunks(chunks: usize, len: usize, seed: u64) -> Vec<u64> {
+        let mut arr = Vec::new();
+        for i in 0..chunks {
+            let mut chunk = random_vec(len, 1_000_000, seed + i as u64);
+            chunk.sort();
+            arr.extend(chunk);
+        }
+        arr
+    }
+
+    #[test]
+    fn test_tim_sort() {
+        let mut arr = vec![5, 2, 9, 1, 5, 6];
+        tim_sort(&mut arr);
+        assert_eq!(arr, vec![1, 2, 5, 5, 6, 9]);
+
+        for len in [0, 1, 2, 63, 64, 65, 100, 1000, 5000] {
+            let input = random_vec(len, 100, len as u64 + 91);
+            let mut expected = input.clone();
+            expected.sort();
+
+            let mut arr = input.clone();
+            tim_sort(&mut arr);
+            assert_eq!(arr, expected, "len = {}", len);
+
+            let mut arr = input;
+            tim_sort_with_order(&mut arr, Order::Descending);
+            expected.reverse();
+            assert_eq!(arr, expected, "len = {}", len);
+        }
+    }
+
+    #[test]
+    fn test_linear_on_sorted_input() {
+        let n = 10_000;
+        let mut arr: Vec<u64> = (0..n).collect();
+        assert_eq!(count_comparisons(&mut arr), n as usize - 1);
+
+        let mut arr: Vec<u64> = (0..n).rev().collect();
+        assert_eq!(count_comparisons(&mut arr), n as usize - 1);
+        assert!(arr.windows(2).all(|w| w[0] < w[1]));
+    }
+
+    #[test]
+    fn test_sorted_chunks() {
+        // 有序段拼接而成的输入比普通归并排序少得多的比较
+        let input = sorted_chunks(8, 5000, 92);
+        let mut expected = input.clone();
+        expected.sort();
+
+        let mut arr = input.clone();
+        let ours = count_comparisons(&mut arr);
+        assert_eq!(arr, expected);
+
+        let mut arr = input;
+        let mut theirs = 0;
+        merge_sort_by(&mut arr, |a, b| {
+            theirs += 1;
+            a.cmp(b)
+        });
+        assert!(
+            ours * 2 < theirs,
+            "tim_sort {} 次，merge_sort {} 次",
+            ours,
+            theirs
+        );
+    }
+
+    #[test]
+    fn test_galloping() {
+        // 两个有序段很少交错：右段的每个元素前面都有 100 个左段元素，
+        // 飞奔模式可以整块移动，合并的比较次数远少于两段长度之和
+        let left: Vec<u64> = (0..3000).map(|i| i * 2).collect();
+        let right: Vec<u64> = (0..30).map(|i| i * 200 + 1).collect();
+        let mut input = left.clone();
+        input.extend(&right);
+        let mut expected = input.clone();
+        expected.sort();
+
+        let mut arr = input.clone();
+        let comparisons = count_comparisons(&mut arr);
+        assert_eq!(arr, expected);
+
+        // 找出两个有序段需要 n - 1 次比较，其余都花在合并上
+        let merge_comparisons = comparisons - (input.len() - 1);
+        assert!(
+            merge_comparisons * 4 < left.len() + right.len(),
+            "合并比较 {} 次",
+            merge_comparisons
+        );
+    }
+
+    #[test]
+    fn test_stable() {
+        // 只有少量不同的键，大量相等元素跨越多个有序段
+        for keys in [
+            random_vec(3000, 5, 93),
+            sorted_chunks(6, 500, 94).iter().map(|k| k % 7).collect(),
+            (0..3000).map(|i| (3000 - i) / 100).collect(),
+        ] {
+            let (mut arr, drops) = tracked_vec(&keys);
+            tim_sort(&mut arr);
+            assert!(arr
+                .windows(2)
+                .all(|w| (w[0].key, w[0].id) < (w[1].key, w[1].id)));
+            assert_each_once(arr, &drops);
+
+            let (mut arr, drops) = tracked_vec(&keys);
+            tim_sort_with_order(&mut arr, Order::Descending);
+            assert!(arr
+                .windows(2)
+                .all(|w| w[0].key > w[1].key || (w[0].key == w[1].key && w[0].id < w[1].id)));
+            assert_each_once(arr, &drops);
+        }
+    }
+
+    #[test]
+    fn test_panic_in_compare_keeps_every_element() {
+        let keys = sorted_chunks(5, 300, 95);
+        let mut total = 0;
+        tim_sort_by(&mut keys.clone(), |a, b| {
+            total += 1;
+            a.cmp(b)
+        });
+
+        // 在合并的不同阶段 panic，包括 merge_lo、merge_hi 和飞奔模式
+        for limit in (1..10).map(|i| total * i / 10) {
+            let (mut arr, drops) = tracked_vec(&keys);
+            let mut calls = 0;
+            let result = panic::catch_unwind(AssertUnwindSafe(|| {
+                tim_sort_by(&mut arr, |a: &Tracked, b: &Tracked| {
+                    calls += 1;
+                    assert!(calls < limit, "比较函数 panic");
+                    a.cmp(b)
+                })
+            }));
+            assert!(result.is_err(), "limit = {}", limit);
+            assert_each_once(arr, &drops);
+        }
+    }
+
+    #[test]
+    fn test_zero_sized_elements() {
+        #[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+        struct Zst;
+
+        let mut arr: Vec<Zst> = (0..300).map(|_| Zst).collect();
+        tim_sort(&mut arr);
+        assert_eq!(arr.len(), 300);
+
+        // 比较结果不一致时会产生很多段并进入合并和飞奔
+        let mut state = 0x9E37_79B9_7F4A_7C15u64;
+        tim_sort_by(&mut arr, |_, _| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            if state & 1 == 0 {
+                Ordering::Less
+            } else {
+                Ordering::Greater
+            }
+        });
+        assert_eq!(arr.len(), 300);
+    }
+
+    #[test]
+    fn test_gallop() {
+        let arr = [1, 2, 2, 3, 5, 8, 8, 8, 9];
+        for key in 0..11 {
+            let less = arr.iter().filter(|&&x| x < key).count();
+            assert_eq!(gallop(&arr, |&x| x < key, false), less);
+            assert_eq!(gallop(&arr, |&x| x < key, true), less);
+        }
+        assert_eq!(gallop(&[] as &[u32], |_| true, true), 0);
+    }
+}